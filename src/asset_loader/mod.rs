@@ -1,28 +1,49 @@
-use bevy::asset::{AssetLoader, LoadedAsset};
+use std::marker::PhantomData;
 
-// ───── Current Crate Imports ────────────────────────────────────────────── //
-
-use crate::game::enemy::assets::DogData;
+use bevy::{
+    asset::{AssetLoader, LoadedAsset},
+    reflect::TypeUuid,
+};
+use serde::de::DeserializeOwned;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-#[derive(Default)]
-pub struct JsonAssetLoader;
+/// Asset which is deserialized from a json file by `JsonAssetLoader`.
+pub trait JsonAsset: TypeUuid + DeserializeOwned + Send + Sync + 'static {
+    /// Compound extensions are allowed, e.g. `progression.json` matches
+    /// `default.progression.json`.
+    const EXTENSIONS: &'static [&'static str];
+
+    /// Checks data which json format can't express, the asset fails to
+    /// load on error.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+pub struct JsonAssetLoader<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for JsonAssetLoader<T> {
+    fn default() -> Self {
+        JsonAssetLoader(PhantomData)
+    }
+}
 
-impl AssetLoader for JsonAssetLoader {
+impl<T: JsonAsset> AssetLoader for JsonAssetLoader<T> {
     fn load<'a>(
         &'a self,
         bytes: &'a [u8],
         load_context: &'a mut bevy::asset::LoadContext,
     ) -> bevy::utils::BoxedFuture<'a, Result<(), bevy::asset::Error>> {
         Box::pin(async move {
-            let custom_asset = serde_json::from_slice::<DogData>(bytes)?;
+            let custom_asset = serde_json::from_slice::<T>(bytes)?;
+            custom_asset.validate().map_err(bevy::asset::Error::msg)?;
             load_context.set_default_asset(LoadedAsset::new(custom_asset));
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        &["json"]
+        T::EXTENSIONS
     }
 }
@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 use kira::clock::ClockHandle;
 use kira::manager::{
     backend::DefaultBackend, AudioManager, AudioManagerSettings,
@@ -21,6 +21,7 @@ pub struct SoundHandleResource {
     pub title_theme: Option<StaticSoundHandle>,
     pub main_theme: Option<StaticSoundHandle>,
     pub gameover_theme: Option<StaticSoundHandle>,
+    pub boss_theme: Option<StaticSoundHandle>,
     pub main_theme_clock: Option<ClockHandle>,
 }
 
@@ -178,3 +179,11 @@ impl DerefMut for KiraManager {
         &mut self.manager
     }
 }
+
+/// Everything a system needs to play a sample from the pack.
+#[derive(SystemParam)]
+pub struct Sfx<'w> {
+    pub kira_manager: NonSendMut<'w, KiraManager>,
+    pub audio_assets: Res<'w, Assets<AudioSource>>,
+    pub sample_pack: Res<'w, SamplePack>,
+}
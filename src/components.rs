@@ -18,17 +18,18 @@ pub struct BackgroundStars;
 #[derive(Component)]
 pub struct DarkScreenOverlap;
 
+/// Backdrop which moves along with the camera.
 #[derive(Component)]
-pub struct Comet {
-    pub speed_modifier: f32,
-    pub resolution: Vec2,
+pub struct BackgroundLayer {
+    /// Share of the camera movement the layer repeats, `1.` keeps it still
+    /// on the screen, less makes it drift like something far away.
+    pub follow: f32,
 }
 
 #[derive(Component)]
-pub struct DustParticle {
-    pub direction: Vec2,
-    pub velocity: f32,
-    pub timer: Timer,
+pub struct Comet {
+    pub speed_modifier: f32,
+    pub resolution: Vec2,
 }
 
 #[derive(Component)]
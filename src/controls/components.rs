@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::resources::Action;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+#[derive(Component)]
+pub struct ControlsMenu;
+
+/// Row of the controls screen, clicking it waits for a new key.
+#[derive(Component)]
+pub struct RebindButton {
+    pub action: Action,
+}
+
+/// Text with keys currently bound to `action`.
+#[derive(Component)]
+pub struct BindingLabel {
+    pub action: Action,
+}
+
+/// Menu button which can be selected with gamepad d-pad. Buttons are
+/// visited in `order`, `color` is restored when focus leaves the button.
+#[derive(Component)]
+pub struct Focusable {
+    pub order: u32,
+    pub color: Color,
+}
+
+#[derive(Component)]
+pub struct ResetBindingsButton;
+
+#[derive(Component)]
+pub struct ControlsBackButton;
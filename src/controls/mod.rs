@@ -0,0 +1,67 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::resources::{ActionState, InputMap, MenuFocus, RebindTarget};
+use self::systems::*;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+pub mod components;
+pub mod resources;
+mod styles;
+pub mod systems;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Maps keyboard to game actions and provides the screen to rebind them.
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<InputMap>()
+            .init_resource::<ActionState>()
+            .init_resource::<RebindTarget>()
+            .init_resource::<MenuFocus>()
+            // Input Systems
+            .add_system(
+                update_action_state
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(InputSystem),
+            )
+            // Menu Navigation Systems
+            .add_system(
+                navigate_menu_with_gamepad
+                    .in_base_set(CoreSet::PreUpdate)
+                    .after(update_action_state)
+                    .after(UiSystem::Focus),
+            )
+            .add_system(highlight_focused_button)
+            // Enter State Systems
+            .add_system(
+                spawn_controls_menu.in_schedule(OnEnter(AppState::Controls)),
+            )
+            // Interaction Systems
+            .add_systems(
+                (
+                    leave_controls_on_back,
+                    interact_with_rebind_buttons,
+                    interact_with_reset_button,
+                    interact_with_back_button,
+                    capture_rebind_key,
+                    update_binding_labels,
+                )
+                    .chain()
+                    .in_set(OnUpdate(AppState::Controls)),
+            )
+            // Exit State Systems
+            .add_system(
+                despawn_controls_menu.in_schedule(OnExit(AppState::Controls)),
+            );
+    }
+}
@@ -0,0 +1,543 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::player::components::PlayerId;
+use crate::storage::{
+    check_version, default_backend, StorageBackend, StorageError,
+};
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+/// Version of the stored bindings format. Bump it when `BindingsFile`
+/// layout changes in an incompatible way.
+pub const BINDINGS_FORMAT_VERSION: u32 = 2;
+
+/// Name of the bindings file (or `localStorage` key on the web).
+pub const BINDINGS_STORAGE_NAME: &str = "controls";
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Everything player can ask the game to do, independent of keys.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub enum Action {
+    ThrustUp,
+    ThrustDown,
+    ThrustLeft,
+    ThrustRight,
+    Fire,
+    Pause,
+    Confirm,
+    Back,
+    /// Gameplay actions of the second Kitty in co-op.
+    SecondThrustUp,
+    SecondThrustDown,
+    SecondThrustLeft,
+    SecondThrustRight,
+    SecondFire,
+}
+
+impl Action {
+    pub const ALL: [Action; 13] = [
+        Action::ThrustUp,
+        Action::ThrustDown,
+        Action::ThrustLeft,
+        Action::ThrustRight,
+        Action::Fire,
+        Action::SecondThrustUp,
+        Action::SecondThrustDown,
+        Action::SecondThrustLeft,
+        Action::SecondThrustRight,
+        Action::SecondFire,
+        Action::Pause,
+        Action::Confirm,
+        Action::Back,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::ThrustUp => "Thrust up",
+            Action::ThrustDown => "Thrust down",
+            Action::ThrustLeft => "Thrust left",
+            Action::ThrustRight => "Thrust right",
+            Action::Fire => "Fire",
+            Action::Pause => "Pause",
+            Action::Confirm => "Confirm",
+            Action::Back => "Back",
+            Action::SecondThrustUp => "P2 thrust up",
+            Action::SecondThrustDown => "P2 thrust down",
+            Action::SecondThrustLeft => "P2 thrust left",
+            Action::SecondThrustRight => "P2 thrust right",
+            Action::SecondFire => "P2 fire",
+        }
+    }
+
+    pub fn default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            Action::ThrustUp => vec![KeyCode::W],
+            Action::ThrustDown => vec![KeyCode::S],
+            Action::ThrustLeft => vec![KeyCode::A],
+            Action::ThrustRight => vec![KeyCode::D],
+            Action::Fire => vec![KeyCode::F],
+            Action::Pause => vec![KeyCode::Space],
+            Action::Confirm => vec![KeyCode::Return],
+            Action::Back => vec![KeyCode::Escape],
+            Action::SecondThrustUp => vec![KeyCode::Up],
+            Action::SecondThrustDown => vec![KeyCode::Down],
+            Action::SecondThrustLeft => vec![KeyCode::Left],
+            Action::SecondThrustRight => vec![KeyCode::Right],
+            Action::SecondFire => vec![KeyCode::RControl],
+        }
+    }
+
+    /// Same gameplay action of `player`, menu actions are shared.
+    pub fn of_player(&self, player: PlayerId) -> Action {
+        match (player, self) {
+            (PlayerId::Two, Action::ThrustUp) => Action::SecondThrustUp,
+            (PlayerId::Two, Action::ThrustDown) => Action::SecondThrustDown,
+            (PlayerId::Two, Action::ThrustLeft) => Action::SecondThrustLeft,
+            (PlayerId::Two, Action::ThrustRight) => Action::SecondThrustRight,
+            (PlayerId::Two, Action::Fire) => Action::SecondFire,
+            _ => *self,
+        }
+    }
+
+    /// Buttons of any connected gamepad which trigger the action.
+    pub fn gamepad_buttons(&self) -> &'static [GamepadButtonType] {
+        match self {
+            Action::ThrustUp => &[GamepadButtonType::DPadUp],
+            Action::ThrustDown => &[GamepadButtonType::DPadDown],
+            Action::ThrustLeft => &[GamepadButtonType::DPadLeft],
+            Action::ThrustRight => &[GamepadButtonType::DPadRight],
+            Action::Fire => {
+                &[GamepadButtonType::West, GamepadButtonType::RightTrigger2]
+            }
+            Action::Pause => &[GamepadButtonType::Start],
+            Action::Confirm => &[GamepadButtonType::South],
+            Action::Back => &[GamepadButtonType::Select],
+            // Gamepad of the second Kitty uses the first Kitty's buttons
+            _ => &[],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BindingsFile {
+    version: u32,
+    bindings: BTreeMap<Action, Vec<KeyCode>>,
+}
+
+/// Keys bound to every `Action`, stored between sessions.
+#[derive(Resource)]
+pub struct InputMap {
+    backend: Box<dyn StorageBackend>,
+    bindings: BTreeMap<Action, Vec<KeyCode>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        InputMap::load(default_backend(BINDINGS_STORAGE_NAME))
+    }
+}
+
+impl InputMap {
+    /// Reads stored bindings from `backend`. Actions missing in stored data
+    /// get default keys, broken data is replaced with defaults entirely.
+    pub fn load(backend: Box<dyn StorageBackend>) -> Self {
+        let stored = match backend.read().and_then(|data| match data {
+            Some(data) => deserialize_bindings(&data),
+            None => Ok(BTreeMap::new()),
+        }) {
+            Ok(bindings) => bindings,
+            Err(e) => {
+                warn!("Can't load key bindings, using defaults: {}", e);
+                BTreeMap::new()
+            }
+        };
+        let mut input_map = InputMap {
+            backend,
+            bindings: default_bindings(),
+        };
+        input_map.bindings.extend(stored);
+        input_map
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.bindings
+            .get(&action)
+            .map_or(&[], |keys| keys.as_slice())
+    }
+
+    /// Makes `key` the only key of `action`, removing it from other
+    /// actions so one key never triggers two of them. Action for which
+    /// `key` was the only one takes over the previous keys of `action`, and
+    /// the rebind is refused if there are none, so no action is left
+    /// without keys.
+    pub fn rebind(&mut self, action: Action, key: KeyCode) -> bool {
+        let freed: Vec<_> = self
+            .keys(action)
+            .iter()
+            .copied()
+            .filter(|bound| *bound != key)
+            .collect();
+        let orphans: Vec<_> = self
+            .bindings
+            .iter()
+            .filter(|(other, keys)| **other != action && keys[..] == [key])
+            .map(|(other, _)| *other)
+            .collect();
+        match orphans[..] {
+            [] => {}
+            [_] if !freed.is_empty() => {}
+            _ => return false,
+        }
+
+        for keys in self.bindings.values_mut() {
+            keys.retain(|bound| *bound != key);
+        }
+        for orphan in orphans {
+            self.bindings.insert(orphan, freed.clone());
+        }
+        self.bindings.insert(action, vec![key]);
+        true
+    }
+
+    pub fn reset(&mut self) {
+        self.bindings = default_bindings();
+    }
+
+    pub fn save(&self) -> Result<(), StorageError> {
+        let file = BindingsFile {
+            version: BINDINGS_FORMAT_VERSION,
+            bindings: self.bindings.clone(),
+        };
+        let data = serde_json::to_string_pretty(&file)
+            .map_err(|e| StorageError::Format(e.to_string()))?;
+        self.backend.write(&data)
+    }
+}
+
+fn default_bindings() -> BTreeMap<Action, Vec<KeyCode>> {
+    Action::ALL
+        .iter()
+        .map(|action| (*action, action.default_keys()))
+        .collect()
+}
+
+fn deserialize_bindings(
+    data: &str,
+) -> Result<BTreeMap<Action, Vec<KeyCode>>, StorageError> {
+    let migrate = match check_version(data, BINDINGS_FORMAT_VERSION) {
+        Ok(()) => false,
+        Err(StorageError::UnsupportedVersion(1)) => true,
+        Err(e) => return Err(e),
+    };
+
+    let bindings = serde_json::from_str::<BindingsFile>(data)
+        .map(|file| file.bindings)
+        .map_err(|e| StorageError::Format(e.to_string()))?;
+    Ok(if migrate {
+        migrate_from_first_version(bindings)
+    } else {
+        bindings
+    })
+}
+
+/// First version bound arrows to the first Kitty thrust, now they drive
+/// the second Kitty. Thrust without keys left gets its default one.
+fn migrate_from_first_version(
+    mut bindings: BTreeMap<Action, Vec<KeyCode>>,
+) -> BTreeMap<Action, Vec<KeyCode>> {
+    const ARROWS: [KeyCode; 4] =
+        [KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right];
+    const THRUST: [Action; 4] = [
+        Action::ThrustUp,
+        Action::ThrustDown,
+        Action::ThrustLeft,
+        Action::ThrustRight,
+    ];
+    for action in THRUST {
+        if let Some(keys) = bindings.get_mut(&action) {
+            keys.retain(|key| !ARROWS.contains(key));
+            if keys.is_empty() {
+                bindings.remove(&action);
+            }
+        }
+    }
+
+    // Second Kitty gets default keys which the player hasn't taken for
+    // something else
+    let taken: Vec<KeyCode> = bindings.values().flatten().copied().collect();
+    for action in THRUST.iter().chain([&Action::Fire]) {
+        let action = action.of_player(PlayerId::Two);
+        if bindings.contains_key(&action) {
+            continue;
+        }
+        let mut keys = action.default_keys();
+        keys.retain(|key| !taken.contains(key));
+        bindings.insert(action, keys);
+    }
+    bindings
+}
+
+/// Actions triggered this frame, gameplay and menus read it instead of raw
+/// keyboard input.
+#[derive(Resource, Default)]
+pub struct ActionState {
+    pressed: HashSet<Action>,
+    just_pressed: HashSet<Action>,
+    /// Analog thrust of every Kitty from a gamepad stick, overrides digital
+    /// actions.
+    sticks: [Vec2; 2],
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    /// Thrust direction of `player` with length up to 1. Stick gives
+    /// partial thrust, digital actions always give the full one.
+    pub fn thrust(&self, player: PlayerId) -> Vec2 {
+        let stick = self.sticks[player.index()];
+        if stick != Vec2::ZERO {
+            return stick.clamp_length_max(1.);
+        }
+
+        let pressed = |action: Action| self.pressed(action.of_player(player));
+        let mut direction = Vec2::ZERO;
+        if pressed(Action::ThrustLeft) {
+            direction += Vec2::new(-1., 0.);
+        }
+        if pressed(Action::ThrustRight) {
+            direction += Vec2::new(1., 0.);
+        }
+        if pressed(Action::ThrustUp) {
+            direction += Vec2::new(0., 1.);
+        }
+        if pressed(Action::ThrustDown) {
+            direction += Vec2::new(0., -1.);
+        }
+        direction.normalize_or_zero()
+    }
+
+    pub fn update(&mut self, input_map: &InputMap, keyboard: &Input<KeyCode>) {
+        self.pressed.clear();
+        self.just_pressed.clear();
+        self.sticks = [Vec2::ZERO; 2];
+        for action in Action::ALL {
+            let keys = input_map.keys(action);
+            if keyboard.any_pressed(keys.iter().copied()) {
+                self.pressed.insert(action);
+            }
+            if keyboard.any_just_pressed(keys.iter().copied()) {
+                self.just_pressed.insert(action);
+            }
+        }
+    }
+
+    /// Adds input of `gamepad` controlling `player` on top of keyboard one,
+    /// so should be called after `update`.
+    pub fn update_gamepad(
+        &mut self,
+        gamepad: Gamepad,
+        player: PlayerId,
+        buttons: &Input<GamepadButton>,
+        axes: &Axis<GamepadAxis>,
+    ) {
+        for action in Action::ALL {
+            for button_type in action.gamepad_buttons() {
+                let button = GamepadButton::new(gamepad, *button_type);
+                if buttons.pressed(button) {
+                    self.pressed.insert(action.of_player(player));
+                }
+                if buttons.just_pressed(button) {
+                    self.just_pressed.insert(action.of_player(player));
+                }
+            }
+        }
+
+        let axis = |axis_type| {
+            axes.get(GamepadAxis::new(gamepad, axis_type)).unwrap_or(0.)
+        };
+        let stick = Vec2::new(
+            axis(GamepadAxisType::LeftStickX),
+            axis(GamepadAxisType::LeftStickY),
+        );
+        // Strongest stick wins if several gamepads control one Kitty
+        let current = &mut self.sticks[player.index()];
+        if stick.length_squared() > current.length_squared() {
+            *current = stick;
+        }
+    }
+
+    /// Lets the only Kitty of a solo run follow second player's input too,
+    /// so both key sets work. Should be called after all updates.
+    pub fn merge_into_first_player(&mut self) {
+        for action in Action::ALL {
+            let second = action.of_player(PlayerId::Two);
+            if second == action {
+                continue;
+            }
+            if self.pressed.contains(&second) {
+                self.pressed.insert(action);
+            }
+            if self.just_pressed.contains(&second) {
+                self.just_pressed.insert(action);
+            }
+        }
+        let [first, second] = &mut self.sticks;
+        if second.length_squared() > first.length_squared() {
+            *first = *second;
+        }
+    }
+}
+
+/// Menu button selected with gamepad, `Confirm` presses it instead of
+/// starting the game.
+#[derive(Resource, Default)]
+pub struct MenuFocus(pub Option<Entity>);
+
+/// Action which waits for a new key on the controls screen.
+#[derive(Resource, Default)]
+pub struct RebindTarget(pub Option<Action>);
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::storage::MemoryBackend;
+
+    #[test]
+    fn missing_bindings_fall_back_to_defaults() {
+        let input_map = InputMap::load(Box::new(MemoryBackend::default()));
+        for action in Action::ALL {
+            assert_eq!(input_map.keys(action), action.default_keys());
+        }
+    }
+
+    #[test]
+    fn rebound_keys_are_loaded_back() {
+        let backend = MemoryBackend::default();
+        let mut input_map = InputMap::load(Box::new(backend.clone()));
+        // Up arrow moves from second Kitty's thrust up to pause
+        assert!(input_map.rebind(Action::Pause, KeyCode::Up));
+        input_map.save().unwrap();
+
+        let input_map = InputMap::load(Box::new(backend));
+        assert_eq!(input_map.keys(Action::Pause), [KeyCode::Up]);
+        assert_eq!(input_map.keys(Action::ThrustUp), [KeyCode::W]);
+        assert_eq!(input_map.keys(Action::SecondThrustUp), [KeyCode::Space]);
+    }
+
+    #[test]
+    fn rebind_never_leaves_action_without_keys() {
+        let mut input_map = InputMap::load(Box::new(MemoryBackend::default()));
+        // Escape was the only key of Back, which gets Return instead
+        assert!(input_map.rebind(Action::Confirm, KeyCode::Escape));
+        assert_eq!(input_map.keys(Action::Confirm), [KeyCode::Escape]);
+        assert_eq!(input_map.keys(Action::Back), [KeyCode::Return]);
+
+        // Stored bindings may share the only key between two actions,
+        // and Fire has one key to give away
+        input_map
+            .bindings
+            .insert(Action::Pause, vec![KeyCode::Return]);
+        assert!(!input_map.rebind(Action::Fire, KeyCode::Return));
+        assert_eq!(input_map.keys(Action::Fire), [KeyCode::F]);
+        assert_eq!(input_map.keys(Action::Back), [KeyCode::Return]);
+        for action in Action::ALL {
+            assert!(!input_map.keys(action).is_empty());
+        }
+    }
+
+    #[test]
+    fn first_version_arrows_move_to_second_kitty() {
+        let backend = MemoryBackend(Arc::new(Mutex::new(Some(String::from(
+            r#"{"version": 1, "bindings": {
+                "ThrustUp": ["W", "Up"],
+                "ThrustLeft": ["Left"],
+                "Pause": ["Down"]
+            }}"#,
+        )))));
+        let input_map = InputMap::load(Box::new(backend));
+        assert_eq!(input_map.keys(Action::ThrustUp), [KeyCode::W]);
+        assert_eq!(input_map.keys(Action::ThrustLeft), [KeyCode::A]);
+        assert_eq!(input_map.keys(Action::Pause), [KeyCode::Down]);
+        assert_eq!(input_map.keys(Action::SecondThrustUp), [KeyCode::Up]);
+        assert!(input_map.keys(Action::SecondThrustDown).is_empty());
+    }
+
+    #[test]
+    fn action_state_follows_bindings() {
+        let backend = MemoryBackend(Arc::new(Mutex::new(Some(String::from(
+            r#"{"version": 2, "bindings": {"ThrustUp": ["I"]}}"#,
+        )))));
+        let input_map = InputMap::load(Box::new(backend));
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::I);
+        keyboard.press(KeyCode::D);
+
+        let mut state = ActionState::default();
+        state.update(&input_map, &keyboard);
+        assert!(state.just_pressed(Action::ThrustUp));
+        assert!(state.pressed(Action::ThrustRight));
+        assert!(!state.pressed(Action::Pause));
+        assert_eq!(state.thrust(PlayerId::One), Vec2::new(1., 1.).normalize());
+        assert_eq!(state.thrust(PlayerId::Two), Vec2::ZERO);
+    }
+
+    #[test]
+    fn stick_gives_partial_thrust() {
+        let input_map = InputMap::load(Box::new(MemoryBackend::default()));
+        let gamepad = Gamepad::new(0);
+        let mut buttons = Input::<GamepadButton>::default();
+        buttons.press(GamepadButton::new(gamepad, GamepadButtonType::Start));
+        let mut axes = Axis::<GamepadAxis>::default();
+        axes.set(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX), 0.5);
+
+        let mut state = ActionState::default();
+        state.update(&input_map, &Input::default());
+        state.update_gamepad(gamepad, PlayerId::One, &buttons, &axes);
+        assert!(state.just_pressed(Action::Pause));
+        assert_eq!(state.thrust(PlayerId::One), Vec2::new(0.5, 0.));
+    }
+
+    #[test]
+    fn players_have_own_thrust() {
+        let input_map = InputMap::load(Box::new(MemoryBackend::default()));
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::W);
+        keyboard.press(KeyCode::Left);
+
+        let mut state = ActionState::default();
+        state.update(&input_map, &keyboard);
+        assert_eq!(state.thrust(PlayerId::One), Vec2::Y);
+        assert_eq!(state.thrust(PlayerId::Two), Vec2::NEG_X);
+
+        // Solo Kitty follows both key sets
+        state.merge_into_first_player();
+        assert_eq!(state.thrust(PlayerId::One), Vec2::new(-1., 1.).normalize());
+    }
+}
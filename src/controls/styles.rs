@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+// Colors
+pub const BUTTON_COLOR: Color = Color::rgba(0.14, 0.81, 0.82, 0.15);
+pub const HOVERED_BUTTON_COLOR: Color = Color::rgba(0.14, 0.81, 0.82, 0.35);
+pub const LISTENING_BUTTON_COLOR: Color = Color::rgba(0.95, 0.7, 0.2, 0.5);
+pub const TITLE_COLOR: Color = Color::rgb(0.14, 0.81, 0.82);
+pub const FOCUSED_BUTTON_COLOR: Color = Color::rgba(0.5, 1., 1., 0.9);
+
+// Layout
+pub const MAIN_CONTAINER: Style = Style {
+    size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+    flex_direction: FlexDirection::Column,
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    gap: Size::new(Val::Px(0.), Val::Px(4.)),
+    ..Style::DEFAULT
+};
+pub const BINDING_ROW: Style = Style {
+    size: Size::new(Val::Px(520.), Val::Px(34.)),
+    justify_content: JustifyContent::SpaceBetween,
+    align_items: AlignItems::Center,
+    padding: UiRect::new(Val::Px(16.), Val::Px(16.), Val::Px(0.), Val::Px(0.)),
+    ..Style::DEFAULT
+};
+pub const BOTTOM_ROW: Style = Style {
+    gap: Size::new(Val::Px(20.), Val::Px(0.)),
+    margin: UiRect::new(Val::Px(0.), Val::Px(0.), Val::Px(20.), Val::Px(0.)),
+    ..Style::DEFAULT
+};
+pub const TEXT_BUTTON: Style = Style {
+    size: Size::new(Val::Px(200.), Val::Px(50.)),
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    ..Style::DEFAULT
+};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn get_title_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/NicoMoji-Regular.ttf"),
+        font_size: 48.,
+        color: TITLE_COLOR,
+    }
+}
+
+pub fn get_row_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 26.,
+        color: Color::WHITE,
+    }
+}
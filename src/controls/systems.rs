@@ -0,0 +1,367 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::{
+    components::*,
+    resources::{Action, ActionState, InputMap, MenuFocus, RebindTarget},
+    styles::*,
+};
+use crate::game::player::resources::PlayMode;
+use crate::AppState;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn update_action_state(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    input_map: Res<InputMap>,
+    play_mode: Res<PlayMode>,
+    mut action_state: ResMut<ActionState>,
+) {
+    action_state.update(&input_map, &keyboard_input);
+    // Every Kitty gets its own gamepad, extra ones join the last Kitty.
+    // Gamepads are stored unordered, so they're sorted to keep the pairs.
+    let players = play_mode.players();
+    let mut gamepads: Vec<Gamepad> = gamepads.iter().collect();
+    gamepads.sort_by_key(|gamepad| gamepad.id);
+    for (i, gamepad) in gamepads.into_iter().enumerate() {
+        action_state.update_gamepad(
+            gamepad,
+            players[i.min(players.len() - 1)],
+            &gamepad_buttons,
+            &gamepad_axes,
+        );
+    }
+    if *play_mode == PlayMode::Solo {
+        action_state.merge_into_first_player();
+    }
+}
+
+/// Moves focus between `Focusable` buttons with d-pad and presses focused
+/// one on `Confirm`, by setting its `Interaction` for a single frame.
+pub fn navigate_menu_with_gamepad(
+    gamepads: Res<Gamepads>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    action_state: Res<ActionState>,
+    mut menu_focus: ResMut<MenuFocus>,
+    mut focusable_query: Query<(Entity, &Focusable, &mut Interaction)>,
+    mut local_pressed: Local<Option<Entity>>,
+) {
+    // Release button pressed last frame
+    if let Some(entity) = local_pressed.take() {
+        if let Ok((_, _, mut interaction)) = focusable_query.get_mut(entity) {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
+
+    let mut buttons: Vec<(Entity, u32)> = focusable_query
+        .iter()
+        .map(|(entity, focusable, _)| (entity, focusable.order))
+        .collect();
+    buttons.sort_by_key(|(_, order)| *order);
+
+    let position = menu_focus
+        .0
+        .and_then(|focused| buttons.iter().position(|(e, _)| *e == focused));
+    if position.is_none() && menu_focus.0.is_some() {
+        // Focused button was despawned with its menu
+        menu_focus.0 = None;
+    }
+    if buttons.is_empty() {
+        return;
+    }
+
+    let pressed = |button_type| {
+        gamepads.iter().any(|gamepad| {
+            gamepad_buttons
+                .just_pressed(GamepadButton::new(gamepad, button_type))
+        })
+    };
+    let step: i32 = if pressed(GamepadButtonType::DPadUp)
+        || pressed(GamepadButtonType::DPadLeft)
+    {
+        -1
+    } else if pressed(GamepadButtonType::DPadDown)
+        || pressed(GamepadButtonType::DPadRight)
+    {
+        1
+    } else {
+        0
+    };
+
+    if step != 0 {
+        let next = match position {
+            Some(position) => (position as i32 + step)
+                .rem_euclid(buttons.len() as i32)
+                as usize,
+            None => 0,
+        };
+        menu_focus.0 = Some(buttons[next].0);
+    } else if let Some(position) = position {
+        if action_state.just_pressed(Action::Confirm) {
+            let entity = buttons[position].0;
+            if let Ok((_, _, mut interaction)) = focusable_query.get_mut(entity)
+            {
+                *interaction = Interaction::Clicked;
+                *local_pressed = Some(entity);
+            }
+        }
+    }
+}
+
+pub fn highlight_focused_button(
+    menu_focus: Res<MenuFocus>,
+    mut focusable_query: Query<(Entity, &Focusable, &mut BackgroundColor)>,
+) {
+    if !menu_focus.is_changed() {
+        return;
+    }
+    for (entity, focusable, mut color) in focusable_query.iter_mut() {
+        *color = if menu_focus.0 == Some(entity) {
+            FOCUSED_BUTTON_COLOR.into()
+        } else {
+            focusable.color.into()
+        };
+    }
+}
+
+pub fn spawn_controls_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let row_text_style = get_row_text_style(&asset_server);
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_CONTAINER,
+                transform: Transform::from_xyz(0., 0., 5.),
+                ..default()
+            },
+            ControlsMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Controls",
+                get_title_text_style(&asset_server),
+            ));
+
+            for action in Action::ALL {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: BINDING_ROW,
+                            background_color: BUTTON_COLOR.into(),
+                            ..default()
+                        },
+                        RebindButton { action },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            action.label(),
+                            row_text_style.clone(),
+                        ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                row_text_style.clone(),
+                            ),
+                            BindingLabel { action },
+                        ));
+                    });
+            }
+
+            parent
+                .spawn(NodeBundle {
+                    style: BOTTOM_ROW,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_text_button(
+                        parent,
+                        "Reset",
+                        &row_text_style,
+                        ResetBindingsButton,
+                    );
+                    spawn_text_button(
+                        parent,
+                        "Back",
+                        &row_text_style,
+                        ControlsBackButton,
+                    );
+                });
+        });
+}
+
+fn spawn_text_button(
+    parent: &mut ChildBuilder,
+    text: &str,
+    text_style: &TextStyle,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: TEXT_BUTTON,
+                background_color: BUTTON_COLOR.into(),
+                ..default()
+            },
+            marker,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(text, text_style.clone()));
+        });
+}
+
+pub fn despawn_controls_menu(
+    mut commands: Commands,
+    controls_menu_query: Query<Entity, With<ControlsMenu>>,
+    mut rebind_target: ResMut<RebindTarget>,
+) {
+    if let Ok(controls_menu) = controls_menu_query.get_single() {
+        commands.entity(controls_menu).despawn_recursive();
+    }
+    rebind_target.0 = None;
+}
+
+pub fn interact_with_rebind_buttons(
+    mut button_query: Query<
+        (&Interaction, &RebindButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut rebind_target: ResMut<RebindTarget>,
+) {
+    for (interaction, button, mut color) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                rebind_target.0 = Some(button.action);
+            }
+            Interaction::Hovered => {
+                if rebind_target.0 != Some(button.action) {
+                    *color = HOVERED_BUTTON_COLOR.into();
+                }
+            }
+            Interaction::None => {
+                if rebind_target.0 != Some(button.action) {
+                    *color = BUTTON_COLOR.into();
+                }
+            }
+        }
+    }
+}
+
+pub fn interact_with_reset_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ResetBindingsButton),
+        Changed<Interaction>,
+    >,
+    mut input_map: ResMut<InputMap>,
+    mut rebind_target: ResMut<RebindTarget>,
+) {
+    if let Ok((interaction, mut color, _)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                rebind_target.0 = None;
+                input_map.reset();
+                if let Err(e) = input_map.save() {
+                    warn!("Can't save key bindings: {}", e);
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *color = BUTTON_COLOR.into(),
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ControlsBackButton),
+        Changed<Interaction>,
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut color, _)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => next_state.set(AppState::MainMenu),
+            Interaction::Hovered => *color = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *color = BUTTON_COLOR.into(),
+        }
+    }
+}
+
+/// Leaves controls screen on `Back` action, unless the key is being
+/// captured for rebinding.
+pub fn leave_controls_on_back(
+    action_state: Res<ActionState>,
+    rebind_target: Res<RebindTarget>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if rebind_target.0.is_none() && action_state.just_pressed(Action::Back) {
+        next_state.set(AppState::MainMenu);
+    }
+}
+
+pub fn capture_rebind_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut rebind_target: ResMut<RebindTarget>,
+    mut input_map: ResMut<InputMap>,
+) {
+    let Some(action) = rebind_target.0 else {
+        return;
+    };
+    if let Some(key) = keyboard_input.get_just_pressed().next() {
+        // Key stays captured until the one which frees no action is pressed
+        if !input_map.rebind(action, *key) {
+            warn!("{:?} is the only key of another action", key);
+            return;
+        }
+        if let Err(e) = input_map.save() {
+            warn!("Can't save key bindings: {}", e);
+        }
+        rebind_target.0 = None;
+    }
+}
+
+pub fn update_binding_labels(
+    input_map: Res<InputMap>,
+    rebind_target: Res<RebindTarget>,
+    new_label_query: Query<(), Added<BindingLabel>>,
+    mut label_query: Query<(&mut Text, &BindingLabel)>,
+    mut button_query: Query<(
+        &Interaction,
+        &RebindButton,
+        &mut BackgroundColor,
+    )>,
+) {
+    if !input_map.is_changed()
+        && !rebind_target.is_changed()
+        && new_label_query.is_empty()
+    {
+        return;
+    }
+
+    for (mut text, label) in label_query.iter_mut() {
+        text.sections[0].value = if rebind_target.0 == Some(label.action) {
+            String::from("Press a key...")
+        } else {
+            input_map
+                .keys(label.action)
+                .iter()
+                .map(|key| format!("{:?}", key))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+    }
+
+    for (interaction, button, mut color) in button_query.iter_mut() {
+        *color = if rebind_target.0 == Some(button.action) {
+            LISTENING_BUTTON_COLOR.into()
+        } else if *interaction == Interaction::Hovered {
+            HOVERED_BUTTON_COLOR.into()
+        } else {
+            BUTTON_COLOR.into()
+        };
+    }
+}
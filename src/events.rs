@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::player::components::PlayerId;
+
 // ───── Events ───────────────────────────────────────────────────────────── //
 
 pub struct GameOver;
 
 pub struct PlayerHit {
+    pub player: PlayerId,
     pub remaining_health: u8,
     pub position: Vec3,
     pub hit_normal: Vec2,
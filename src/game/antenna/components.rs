@@ -3,8 +3,8 @@ use bevy::prelude::*;
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 #[derive(Component)]
-pub struct FlyingMilk {
-    pub direction: Vec2,
-    pub rotation: f32,
-    pub covered_distance: f32,
-}
+pub struct Antenna;
+
+/// Point on the antenna plate which emits signal particles.
+#[derive(Component)]
+pub struct AntennaSignal;
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::systems::*;
+use super::SimulationState;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+mod components;
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+const ANTENNA_SIZE: Vec2 = Vec2::new(132. / 3.5, 113. / 3.5);
+/// Where the antenna stick comes out of the starship.
+const ANTENNA_OFFSET: Vec3 = Vec3::new(0., 20., -0.5);
+/// Signal particles are emitted from the antenna plate.
+const SIGNAL_OFFSET: Vec3 = Vec3::new(0., 26., 0.5);
+const ANTENNA_TURN_SPEED: f32 = 6.;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Antenna on the Kitty's starship, which turns to the glass of milk as
+/// soon as it appears.
+pub struct AntennaPlugin;
+
+impl Plugin for AntennaPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Systems
+            .add_systems(
+                (spawn_antenna, toggle_signal_particles)
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_systems(
+                (rotate_antenna, ping_on_milk_detection)
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            );
+    }
+}
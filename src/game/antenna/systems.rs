@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use kira::sound::static_sound::StaticSoundSettings;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::*;
+use super::{ANTENNA_OFFSET, ANTENNA_SIZE, ANTENNA_TURN_SPEED, SIGNAL_OFFSET};
+use crate::audio::assets::AudioSource;
+use crate::audio::resources::{KiraManager, SamplePack};
+use crate::game::pickups::components::{FlyingPickup, PickupKind};
+use crate::game::player::components::Player;
+use crate::game::SimulationState;
+use crate::particles::components::{ParticleEffectKind, ParticleEmitter};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn spawn_antenna(
+    mut commands: Commands,
+    player_query: Query<Entity, Added<Player>>,
+    asset_server: Res<AssetServer>,
+) {
+    for player in player_query.iter() {
+        commands.entity(player).with_children(|parent| {
+            parent
+                .spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(ANTENNA_SIZE),
+                            // Antenna turns around the bottom of its stick
+                            anchor: Anchor::BottomCenter,
+                            ..default()
+                        },
+                        transform: Transform::from_translation(ANTENNA_OFFSET),
+                        texture: asset_server.load("sprites/Antenna front.png"),
+                        ..default()
+                    },
+                    Antenna,
+                    Name::new("Antenna"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        ParticleEmitter::new(ParticleEffectKind::MilkSignal),
+                        SpatialBundle::from_transform(
+                            Transform::from_translation(SIGNAL_OFFSET),
+                        ),
+                        AntennaSignal,
+                    ));
+                });
+        });
+    }
+}
+
+/// Turns antenna to the nearest glass of milk, or back up if there is
+/// no milk around.
+pub fn rotate_antenna(
+    mut antenna_query: Query<
+        (&Parent, &GlobalTransform, &mut Transform),
+        With<Antenna>,
+    >,
+    player_query: Query<&Transform, (With<Player>, Without<Antenna>)>,
+    pickup_query: Query<(&GlobalTransform, &FlyingPickup)>,
+    time: Res<Time>,
+) {
+    for (parent, global_transform, mut transform) in antenna_query.iter_mut() {
+        let Ok(player_transform) = player_query.get(parent.get()) else {
+            continue;
+        };
+        let position = global_transform.translation().truncate();
+
+        let target = pickup_query
+            .iter()
+            .filter(|(_, pickup)| pickup.kind == PickupKind::Milk)
+            .map(|(milk, _)| milk.translation().truncate() - position)
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .map_or(Quat::IDENTITY, |direction| {
+                // Compensate starship rotation, antenna is its child
+                player_transform.rotation.inverse()
+                    * Quat::from_rotation_arc_2d(
+                        Vec2::Y,
+                        direction.normalize_or_zero(),
+                    )
+            });
+
+        let step = (ANTENNA_TURN_SPEED * time.delta_seconds()).min(1.);
+        transform.rotation = transform.rotation.slerp(target, step);
+    }
+}
+
+pub fn ping_on_milk_detection(
+    pickup_query: Query<&FlyingPickup, Added<FlyingPickup>>,
+    antenna_query: Query<(), With<Antenna>>,
+    mut kira_manager: NonSendMut<KiraManager>,
+    audio_assets: Res<Assets<AudioSource>>,
+    sample_pack: Res<SamplePack>,
+) {
+    let new_milk = pickup_query
+        .iter()
+        .any(|pickup| pickup.kind == PickupKind::Milk);
+    if !new_milk || antenna_query.is_empty() {
+        return;
+    }
+
+    // Play ping sound
+    let sound_data = audio_assets
+        .get(&sample_pack.button)
+        .unwrap()
+        .get()
+        .with_settings(
+            StaticSoundSettings::new()
+                .volume(0.5)
+                .playback_rate(2.)
+                .output_destination(kira_manager.get_master()),
+        );
+    kira_manager.play(sound_data).unwrap();
+}
+
+pub fn toggle_signal_particles(
+    pickup_query: Query<&FlyingPickup>,
+    mut emitter_query: Query<&mut ParticleEmitter, With<AntennaSignal>>,
+    simulation_state: Res<State<SimulationState>>,
+) {
+    // Particles keep flying during pause, so stop emitting them
+    let active = pickup_query
+        .iter()
+        .any(|pickup| pickup.kind == PickupKind::Milk)
+        && simulation_state.0 == SimulationState::Running;
+    for mut emitter in emitter_query.iter_mut() {
+        if emitter.active != active {
+            emitter.active = active;
+        }
+    }
+}
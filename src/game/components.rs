@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 // #[derive(Component)] // pub struct Wall;
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 pub enum Wall {
     Top,
     Bottom,
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Multipliers applied to Kitty's base stats. Modifiers of several effects
+/// stack by multiplying.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Modifiers {
+    pub thrust: f32,
+    pub damping: f32,
+    pub pickup_radius: f32,
+}
+
+impl Default for Modifiers {
+    fn default() -> Self {
+        Modifiers {
+            thrust: 1.,
+            damping: 1.,
+            pickup_radius: 1.,
+        }
+    }
+}
+
+impl Modifiers {
+    pub fn stack(self, other: Modifiers) -> Modifiers {
+        Modifiers {
+            thrust: self.thrust * other.thrust,
+            damping: self.damping * other.damping,
+            pickup_radius: self.pickup_radius * other.pickup_radius,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectKind {
+    SpeedBoost,
+}
+
+pub struct StatusEffect {
+    pub kind: EffectKind,
+    pub modifiers: Modifiers,
+    pub timer: Timer,
+}
+
+impl StatusEffect {
+    pub fn new(kind: EffectKind, modifiers: Modifiers, seconds: f32) -> Self {
+        StatusEffect {
+            kind,
+            modifiers,
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+/// Timed effects currently applied to the Kitty. Effects of the same kind
+/// stack, each one expires on its own timer.
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn add(&mut self, effect: StatusEffect) {
+        self.effects.push(effect);
+    }
+
+    /// Advances timers and drops expired effects.
+    pub fn tick(&mut self, delta: Duration) {
+        self.effects
+            .retain_mut(|effect| !effect.timer.tick(delta).finished());
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.effects
+            .iter()
+            .fold(Modifiers::default(), |acc, effect| {
+                acc.stack(effect.modifiers)
+            })
+    }
+
+    pub fn is_active(&self, kind: EffectKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOST: Modifiers = Modifiers {
+        thrust: 2.,
+        damping: 0.5,
+        pickup_radius: 1.5,
+    };
+
+    #[test]
+    fn effects_stack_and_expire_separately() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::new(EffectKind::SpeedBoost, BOOST, 1.));
+        effects.add(StatusEffect::new(EffectKind::SpeedBoost, BOOST, 2.));
+        assert_eq!(effects.modifiers().thrust, 4.);
+        assert_eq!(effects.modifiers().damping, 0.25);
+
+        effects.tick(Duration::from_secs_f32(1.5));
+        assert_eq!(effects.modifiers(), BOOST);
+        assert!(effects.is_active(EffectKind::SpeedBoost));
+
+        effects.tick(Duration::from_secs_f32(1.));
+        assert_eq!(effects.modifiers(), Modifiers::default());
+        assert!(!effects.is_active(EffectKind::SpeedBoost));
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::systems::{apply_status_effects, tick_status_effects};
+use super::SimulationState;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+pub mod components;
+mod systems;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Systems
+            .add_systems(
+                (tick_status_effects, apply_status_effects)
+                    .chain()
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            );
+    }
+}
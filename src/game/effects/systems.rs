@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::StatusEffects;
+use crate::game::player::components::PickupArea;
+use crate::game::player::{PICKUP_RADIUS, PLAYER_LINEAR_DAMPING};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn tick_status_effects(
+    mut effects_query: Query<&mut StatusEffects>,
+    time: Res<Time>,
+) {
+    for mut effects in effects_query.iter_mut() {
+        effects.tick(time.delta());
+    }
+}
+
+/// Updates Kitty's physics and pickup area from its active effects.
+pub fn apply_status_effects(
+    mut player_query: Query<(&StatusEffects, &mut Damping)>,
+    mut pickup_area_query: Query<(&Parent, &mut Collider), With<PickupArea>>,
+) {
+    for (parent, mut collider) in pickup_area_query.iter_mut() {
+        let Ok((effects, mut damping)) = player_query.get_mut(parent.get())
+        else {
+            continue;
+        };
+        let modifiers = effects.modifiers();
+
+        let linear_damping = PLAYER_LINEAR_DAMPING * modifiers.damping;
+        if damping.linear_damping != linear_damping {
+            damping.linear_damping = linear_damping;
+        }
+
+        // Replacing collider resets it in the physics world, so only
+        // do it when radius really changes
+        let radius = PICKUP_RADIUS * modifiers.pickup_radius;
+        if collider.as_ball().map(|ball| ball.radius()) != Some(radius) {
+            *collider = Collider::ball(radius);
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use bevy::reflect::TypeUuid;
 use serde::Deserialize;
 
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::DogType;
+use crate::asset_loader::JsonAsset;
+
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 #[derive(Clone, Debug, Deserialize, TypeUuid)]
@@ -10,7 +15,154 @@ pub struct DogData {
     pub last_names: Vec<String>,
     pub nicknames: Vec<String>,
     pub hellos: Vec<String>,
-    pub potters_hellos: Vec<String>,
     pub wildly_rotations: Vec<String>,
     pub fish_picking: Vec<String>,
+    #[serde(default)]
+    pub getting_wet: Vec<String>,
+    #[serde(default)]
+    pub rare_fish: Vec<String>,
+}
+
+impl JsonAsset for DogData {
+    const EXTENSIONS: &'static [&'static str] = &["json"];
+}
+
+/// List of all dogs which can arrive in the game.
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "0c8f6d52-3a9e-4b71-8e2d-61f4b7a90c3e"]
+pub struct DogRoster {
+    pub dogs: Vec<DogEntry>,
+}
+
+impl JsonAsset for DogRoster {
+    const EXTENSIONS: &'static [&'static str] = &["roster.json"];
+
+    fn validate(&self) -> Result<(), String> {
+        // Game would wait for dogs forever
+        if self.dogs.is_empty() {
+            return Err(String::from("roster has no dogs"));
+        }
+        self.dogs
+            .iter()
+            .filter_map(|dog| Some((dog, dog.spritesheet.as_ref()?)))
+            .try_for_each(|(dog, sheet)| {
+                sheet
+                    .validate()
+                    .map_err(|e| format!("{} spritesheet: {}", dog.id, e))
+            })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DogEntry {
+    pub id: String,
+    /// Fixed name, random one is generated if it is not set.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Face sprite, also shown on the high scores table.
+    pub texture: String,
+    /// Animated sprite used in game instead of `texture`.
+    #[serde(default)]
+    pub spritesheet: Option<SpriteSheetEntry>,
+    pub avatar: String,
+    pub dog_type: DogType,
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+    /// Own greetings, common ones from `DogData` are used if it is empty.
+    #[serde(default)]
+    pub hellos: Vec<String>,
+    #[serde(default = "default_spawn_weight")]
+    pub spawn_weight: f32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpriteSheetEntry {
+    pub path: String,
+    pub tile_size: [f32; 2],
+    pub columns: usize,
+    pub rows: usize,
+    /// Seconds per animation frame.
+    pub frame_time: f32,
+}
+
+impl SpriteSheetEntry {
+    pub fn frame_count(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.frame_count() == 0 {
+            return Err(String::from("no frames"));
+        }
+        if self.tile_size.iter().any(|side| *side <= 0.) {
+            return Err(String::from("tile size must be positive"));
+        }
+        if self.frame_time <= 0. {
+            return Err(String::from("frame time must be positive"));
+        }
+        Ok(())
+    }
+}
+
+fn default_scale() -> f32 {
+    0.5
+}
+
+fn default_spawn_weight() -> f32 {
+    1.
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_roster_is_valid() {
+        let roster: DogRoster = serde_json::from_str(include_str!(
+            "../../../assets/json_data/default.roster.json"
+        ))
+        .unwrap();
+        assert_eq!(roster.dogs.len(), 12);
+
+        let harry = roster.dogs.iter().find(|dog| dog.id == "Harry").unwrap();
+        assert_eq!(harry.dog_type, DogType::Harry);
+        assert_eq!(harry.scale, 0.5);
+        assert!(!harry.hellos.is_empty());
+
+        let big_boy =
+            roster.dogs.iter().find(|dog| dog.id == "BigBoy").unwrap();
+        assert!(big_boy.spritesheet.is_some());
+        assert_eq!(big_boy.spawn_weight, 1.);
+        assert!(roster.validate().is_ok());
+    }
+
+    #[test]
+    fn empty_roster_is_rejected() {
+        let roster: DogRoster =
+            serde_json::from_str(r#"{"dogs": []}"#).unwrap();
+        assert!(roster.validate().is_err());
+    }
+
+    #[test]
+    fn empty_spritesheet_is_rejected() {
+        let roster: DogRoster = serde_json::from_str(
+            r#"{"dogs": [{
+                "id": "Empty",
+                "texture": "face.png",
+                "avatar": "avatar.png",
+                "dog_type": "Ordinary",
+                "spritesheet": {
+                    "path": "sheet.png",
+                    "tile_size": [64, 64],
+                    "columns": 0,
+                    "rows": 3,
+                    "frame_time": 0.1
+                }
+            }]}"#,
+        )
+        .unwrap();
+        assert!(roster.validate().is_err());
+    }
 }
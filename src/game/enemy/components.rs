@@ -1,8 +1,12 @@
+use std::ops::{Range, RangeInclusive};
+
 use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 pub enum DogType {
     Ordinary,
     BigBoy,
@@ -12,12 +16,147 @@ pub enum DogType {
 #[derive(Component)]
 pub struct Enemy {
     pub dog_type: DogType,
+    /// Id of the `DogRoster` entry this dog was made from.
+    pub roster_id: String,
     pub direction: Vec2,
     pub has_collider: bool,
     pub scale: f32,
     pub phrase_timer: Timer,
 }
 
+/// What a dog does when it notices something within its perception radius.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Behaviour {
+    /// Drifts around, slightly changing direction from time to time.
+    Wander,
+    SeekFish,
+    AvoidKitty,
+    ChaseKitty,
+}
+
+/// Steering settings of a dog, drives its direction in `enemy_movement`.
+#[derive(Component)]
+pub struct DogBrain {
+    pub behaviour: Behaviour,
+    pub perception_radius: f32,
+    /// How fast the dog turns to desired direction, in radians per second.
+    pub turn_rate: f32,
+    pub wander_timer: Timer,
+}
+
+impl DogBrain {
+    pub fn new(behaviour: Behaviour, perception_radius: f32) -> Self {
+        DogBrain {
+            behaviour,
+            perception_radius,
+            turn_rate: 1.5,
+            wander_timer: Timer::from_seconds(2., TimerMode::Repeating),
+        }
+    }
+
+    /// Returns direction the dog wants to move in, `None` if there is
+    /// nothing interesting in sight.
+    pub fn desired_direction(
+        &self,
+        position: Vec2,
+        fish: impl Iterator<Item = Vec2>,
+        kitty: Option<Vec2>,
+    ) -> Option<Vec2> {
+        let kitty_in_sight = kitty
+            .map(|kitty| kitty - position)
+            .filter(|to_kitty| to_kitty.length() <= self.perception_radius);
+
+        match self.behaviour {
+            Behaviour::Wander => None,
+            Behaviour::SeekFish => fish
+                .map(|fish| fish - position)
+                .filter(|to_fish| to_fish.length() <= self.perception_radius)
+                .min_by(|a, b| {
+                    a.length_squared().total_cmp(&b.length_squared())
+                })
+                .map(|to_fish| to_fish.normalize_or_zero()),
+            Behaviour::AvoidKitty => {
+                kitty_in_sight.map(|to_kitty| -to_kitty.normalize_or_zero())
+            }
+            Behaviour::ChaseKitty => {
+                kitty_in_sight.map(|to_kitty| to_kitty.normalize_or_zero())
+            }
+        }
+        .filter(|direction| *direction != Vec2::ZERO)
+    }
+}
+
+/// Gameplay parameters of a dog.
+#[derive(Component, Clone, Debug)]
+pub struct DogStats {
+    pub speed: f32,
+    /// Crackers a dog gets for one fish.
+    pub loot: Range<u32>,
+    pub mass: f32,
+    pub restitution: f32,
+    /// Usual spinning speed, sign is chosen randomly.
+    pub angvel: RangeInclusive<f32>,
+    /// Chance to arrive spinning wildly instead.
+    pub wild_spin_chance: f64,
+    /// Seconds between phrases.
+    pub phrase_interval: Range<f32>,
+}
+
+impl DogStats {
+    pub fn phrase_timer(&self) -> Timer {
+        let rand = rand::thread_rng().gen_range(self.phrase_interval.clone());
+        Timer::from_seconds(rand, TimerMode::Once)
+    }
+}
+
+impl DogType {
+    pub fn default_stats(&self) -> DogStats {
+        match self {
+            DogType::Ordinary => DogStats {
+                speed: 10000.,
+                loot: 1..10,
+                mass: 0.4,
+                restitution: 0.,
+                angvel: 0.3..=0.3,
+                wild_spin_chance: 0.04,
+                phrase_interval: 3.0..10.0,
+            },
+            // Heavy and slow, but eats a lot
+            DogType::BigBoy => DogStats {
+                speed: 6000.,
+                loot: 5..15,
+                mass: 2.,
+                restitution: 0.,
+                angvel: 0.05..=0.15,
+                wild_spin_chance: 0.,
+                phrase_interval: 8.0..15.0,
+            },
+            // Quick, bouncy and talkative, but gets crackers by magic only
+            DogType::Harry => DogStats {
+                speed: 13000.,
+                loot: 1..4,
+                mass: 0.3,
+                restitution: 0.8,
+                angvel: 0.5..=1.5,
+                wild_spin_chance: 0.1,
+                phrase_interval: 2.0..6.0,
+            },
+        }
+    }
+
+    pub fn default_brain(&self) -> DogBrain {
+        match self {
+            DogType::Ordinary => DogBrain::new(Behaviour::SeekFish, 300.),
+            DogType::Harry => DogBrain::new(Behaviour::ChaseKitty, 350.),
+            DogType::BigBoy => DogBrain::new(Behaviour::AvoidKitty, 250.),
+        }
+    }
+}
+
+/// Dog hit by water, drifts with the impulse until the timer ends.
+#[derive(Component)]
+pub struct Drenched(pub Timer);
+
 #[derive(Component)]
 pub struct PatchOfLight;
 
@@ -25,11 +164,83 @@ pub struct PatchOfLight;
 pub struct MessageBox(pub Timer, pub Option<Timer>);
 
 #[derive(Component)]
-pub struct BoyAnimation(pub Timer, pub u8);
+/// Frame timer and index of the last frame of a spritesheet dog.
+pub struct SpriteAnimation(pub Timer, pub usize);
 
+/// Edge-of-screen mark for a dog which is out of view.
 #[derive(Component)]
-pub struct NoteParticle {
+pub struct DogLocator {
+    pub dog: Entity,
+    /// Normalized direction from the locator to the dog.
     pub direction: Vec2,
-    pub velocity: f32,
-    pub timer: Timer,
+    pub distance: f32,
+}
+
+/// Child of `DogLocator`, which follows the dog direction.
+#[derive(Component)]
+pub enum LocatorMark {
+    Arrow,
+    Distance,
+}
+
+/// Child of the dog, which emits notes while it sings.
+#[derive(Component)]
+pub struct NoteEmitter;
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_stats_are_usable() {
+        for dog_type in [DogType::Ordinary, DogType::BigBoy, DogType::Harry] {
+            let stats = dog_type.default_stats();
+            assert!(!stats.loot.is_empty());
+            assert!(!stats.angvel.is_empty());
+            assert!(!stats.phrase_interval.is_empty());
+            assert!((0.0..=1.0).contains(&stats.wild_spin_chance));
+            assert!(stats.mass > 0.);
+        }
+        let ordinary = DogType::Ordinary.default_stats();
+        let big_boy = DogType::BigBoy.default_stats();
+        assert!(big_boy.mass > ordinary.mass);
+        assert!(big_boy.speed < ordinary.speed);
+    }
+
+    #[test]
+    fn seeks_nearest_fish_in_sight() {
+        let brain = DogBrain::new(Behaviour::SeekFish, 100.);
+        let fish =
+            [Vec2::new(50., 0.), Vec2::new(0., 20.), Vec2::new(5., 500.)];
+        assert_eq!(
+            brain.desired_direction(Vec2::ZERO, fish.into_iter(), None),
+            Some(Vec2::Y)
+        );
+        assert_eq!(
+            brain.desired_direction(Vec2::ZERO, std::iter::empty(), None),
+            None
+        );
+    }
+
+    #[test]
+    fn reacts_on_kitty_only_within_perception_radius() {
+        let chase = DogBrain::new(Behaviour::ChaseKitty, 100.);
+        let avoid = DogBrain::new(Behaviour::AvoidKitty, 100.);
+        let kitty = Some(Vec2::new(30., 0.));
+        let nowhere = std::iter::empty;
+        assert_eq!(
+            chase.desired_direction(Vec2::ZERO, nowhere(), kitty),
+            Some(Vec2::X)
+        );
+        assert_eq!(
+            avoid.desired_direction(Vec2::ZERO, nowhere(), kitty),
+            Some(-Vec2::X)
+        );
+        assert_eq!(
+            chase.desired_direction(Vec2::new(-200., 0.), nowhere(), kitty),
+            None
+        );
+    }
 }
@@ -2,8 +2,13 @@ use bevy::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
-use self::{assets::DogData, resources::DogResource, systems::*};
-use super::SimulationState;
+use self::{
+    assets::{DogData, DogRoster},
+    components::DogType,
+    resources::DogResource,
+    systems::*,
+};
+use super::{systems::follow_players_with_camera, SimulationState};
 use crate::AppState;
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
@@ -15,7 +20,10 @@ pub mod systems;
 
 // ───── Constants ────────────────────────────────────────────────────────── //
 
-const ENEMY_SPEED: f32 = 10000.;
+const DOG_ROSTER_PATH: &str = "json_data/default.roster.json";
+const LOCATOR_MARGIN: f32 = 48.;
+const LOCATOR_AVATAR_SIZE: f32 = 56.;
+const LOCATOR_ARROW_OFFSET: f32 = 42.;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -26,8 +34,10 @@ impl Plugin for EnemyPlugin {
         app
             // Assets
             .add_asset::<DogData>()
+            .add_asset::<DogRoster>()
             // Events
             .add_event::<EnemyIsArrivingEvent>()
+            .add_event::<DogSpawnRequest>()
             .add_event::<MessageBoxRequest>()
             .add_event::<DoggyTheme>()
             // Enter State Systems
@@ -36,23 +46,34 @@ impl Plugin for EnemyPlugin {
             .add_systems(
                 (
                     update_enemy_direction,
+                    steer_enemies.before(enemy_movement),
                     enemy_movement,
                     enemy_chatting,
+                    enemy_splash_reaction,
+                    enemy_rare_fish_reaction,
+                    dry_enemies,
                     spawn_enemy_on_game_progress,
                     rotate_patch_of_light,
                     update_message_box,
                     system_add_collider_to_enemy,
                     spawn_message_box,
                     emit_notes,
-                    poll_and_despawn_notes,
                 )
                     .in_set(OnUpdate(AppState::Game))
                     .in_set(OnUpdate(SimulationState::Running)),
             )
-            .add_system(animate_big_boy.in_set(OnUpdate(AppState::Game)))
+            .add_systems(
+                (
+                    load_roster_sprites,
+                    animate_dog_sprites,
+                    update_dog_locators.after(follow_players_with_camera),
+                    update_dog_locator_marks.after(update_dog_locators),
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            )
             // Exit State Systems
             .add_systems(
-                (despawn_enemies, despawn_notes_on_exit)
+                (despawn_enemies, despawn_dog_locators)
                     .in_schedule(OnExit(AppState::Game)),
             );
     }
@@ -61,6 +82,11 @@ impl Plugin for EnemyPlugin {
 // Events
 pub struct EnemyIsArrivingEvent(pub String);
 
+/// Asks to spawn a new dog of one of `dog_types`, any dog if it is empty.
+pub struct DogSpawnRequest {
+    pub dog_types: Vec<DogType>,
+}
+
 pub struct MessageBoxRequest(Entity, String);
 
 pub struct DoggyTheme;
@@ -2,18 +2,28 @@ use bevy::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
-use super::assets::DogData;
+use super::assets::{DogData, DogEntry, DogRoster};
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
+/// Roster entry with its sprites loaded.
 pub struct OneDog {
-    pub texture_identifier: String,
+    pub entry: DogEntry,
     pub texture: Handle<Image>,
     pub avatar: Handle<Image>,
+    pub texture_atlas: Option<Handle<TextureAtlas>>,
 }
 
 #[derive(Resource, Default)]
 pub struct DogResource {
     pub json_data: Handle<DogData>,
+    pub roster: Handle<DogRoster>,
+    /// Stays empty until `roster` is loaded.
     pub dogs: Vec<OneDog>,
 }
+
+impl DogResource {
+    pub fn get(&self, id: &str) -> Option<&OneDog> {
+        self.dogs.iter().find(|dog| dog.entry.id == id)
+    }
+}
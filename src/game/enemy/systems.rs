@@ -1,7 +1,10 @@
 use std::time::Duration;
 
 use bevy::{
-    prelude::*, sprite::Anchor, text::Text2dBounds, utils::HashSet,
+    prelude::*,
+    sprite::Anchor,
+    text::Text2dBounds,
+    utils::{HashMap, HashSet},
     window::PrimaryWindow,
 };
 use bevy_rapier2d::prelude::*;
@@ -11,66 +14,77 @@ use rand::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
+use super::assets::DogRoster;
 use super::components::{
-    DogType, Enemy, MessageBox, NoteParticle, PatchOfLight,
+    DogBrain, DogLocator, DogStats, DogType, Drenched, Enemy, LocatorMark,
+    MessageBox, NoteEmitter, PatchOfLight, SpriteAnimation,
 };
 use super::resources::OneDog;
 use super::*;
 use crate::audio::assets::AudioSource;
 use crate::audio::resources::SamplePack;
-use crate::game::enemy::components::BoyAnimation;
-use crate::game::fish::components::FishWasPickedEvent;
+use crate::game::fish::components::{Fish, FishKind, FishWasPickedEvent};
 use crate::game::gui::components::Avatar;
-use crate::game::score::ScoreUpdateEvent;
+use crate::game::player::components::Player;
+use crate::game::resources::Arena;
+use crate::game::water_gun::DogSplashedEvent;
 use crate::helper_functions::*;
-use crate::resources::TextureStorage;
+use crate::particles::components::{ParticleEffectKind, ParticleEmitter};
 use crate::{audio::resources::KiraManager, game::player::DOG_SIZE};
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 enum PhraseType {
-    Hello(DogType),
+    /// Greeting of the roster dog with given id.
+    Hello(String),
     Rotation,
     Picking,
+    Splash,
+    RareFish,
 }
 
 pub fn load_resources(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let names: Handle<DogData> = asset_server.load("json_data/dogs_data.json");
-    let mut dogs: Vec<OneDog> = Vec::new();
-    let mut avatars: Vec<Handle<Image>> = Vec::new();
-
-    for i in 1..11 {
-        let id = format!("{}", i);
-        dogs.push(OneDog {
-            texture_identifier: id.clone(),
-            texture: asset_server.load(format!("sprites/Dogs/Face{}.png", id)),
-            avatar: asset_server
-                .load(format!("sprites/Avatars/Frame Dog {}.png", id)),
-        });
-    }
-
-    let id = String::from("FaceHarry");
-    dogs.push(OneDog {
-        texture_identifier: id.clone(),
-        texture: asset_server.load(format!("sprites/Dogs/{}.png", id)),
-        avatar: asset_server.load("sprites/Avatars/Frame Harry.png"),
-    });
-    let id = String::from("FaceBigBoy");
-    dogs.push(OneDog {
-        texture_identifier: id.clone(),
-        texture: asset_server.load(format!("sprites/Dogs/{}.png", id)),
-        avatar: asset_server.load("sprites/Avatars/Frame BigBoy.png"),
+    commands.insert_resource(DogResource {
+        json_data: asset_server.load("json_data/dogs_data.json"),
+        roster: asset_server.load(DOG_ROSTER_PATH),
+        dogs: Vec::new(),
     });
+}
 
-    let mut rng: StdRng =
-        rand::SeedableRng::from_seed(rand::thread_rng().gen());
-    dogs.shuffle(&mut rng);
-    avatars.shuffle(&mut rng);
+/// Loads sprites of the roster dogs as soon as the roster itself is loaded.
+pub fn load_roster_sprites(
+    mut dogs_resource: ResMut<DogResource>,
+    rosters: Res<Assets<DogRoster>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+) {
+    if !dogs_resource.dogs.is_empty() {
+        return;
+    }
+    let Some(roster) = rosters.get(&dogs_resource.roster) else {
+        return;
+    };
 
-    commands.insert_resource(DogResource {
-        json_data: names,
-        dogs,
-    });
+    let dogs = roster
+        .dogs
+        .iter()
+        .map(|entry| OneDog {
+            texture: asset_server.load(entry.texture.as_str()),
+            avatar: asset_server.load(entry.avatar.as_str()),
+            texture_atlas: entry.spritesheet.as_ref().map(|sheet| {
+                texture_atlases.add(TextureAtlas::from_grid(
+                    asset_server.load(sheet.path.as_str()),
+                    Vec2::from(sheet.tile_size),
+                    sheet.columns,
+                    sheet.rows,
+                    None,
+                    None,
+                ))
+            }),
+            entry: entry.clone(),
+        })
+        .collect();
+    dogs_resource.dogs = dogs;
 }
 
 pub fn despawn_enemies(
@@ -82,18 +96,59 @@ pub fn despawn_enemies(
     }
 }
 
+/// Lets dog brains choose direction, which `enemy_movement` then follows.
+pub fn steer_enemies(
+    mut enemy_query: Query<(&mut Enemy, &GlobalTransform, &mut DogBrain)>,
+    fish_query: Query<&GlobalTransform, With<Fish>>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    time: Res<Time>,
+) {
+    for (mut enemy, transform, mut brain) in enemy_query.iter_mut() {
+        // Steer only inside of the arena, dogs should fly in first
+        if enemy.has_collider {
+            let position = transform.translation().truncate();
+            // Every dog cares about the nearest Kitty only
+            let kitty = player_query
+                .iter()
+                .map(|transform| transform.translation().truncate())
+                .min_by(|a, b| {
+                    a.distance_squared(position)
+                        .total_cmp(&b.distance_squared(position))
+                });
+            steer_enemy(
+                &mut enemy,
+                &mut brain,
+                position,
+                fish_query.iter().map(|fish| fish.translation().truncate()),
+                kitty,
+                time.delta_seconds(),
+            );
+        }
+    }
+}
+
 pub fn enemy_movement(
-    mut enemy_query: Query<(Entity, Option<&mut Velocity>, &mut Enemy)>,
+    mut enemy_query: Query<(
+        Entity,
+        Option<&mut Velocity>,
+        &mut Enemy,
+        &DogStats,
+    )>,
+    drenched_query: Query<(), With<Drenched>>,
     time: Res<Time>,
     mut message_box_request: EventWriter<MessageBoxRequest>,
     dogs_resource: Res<DogResource>,
     assets: Res<Assets<DogData>>,
 ) {
-    for (entity, velocity, mut enemy) in enemy_query.iter_mut() {
+    for (entity, velocity, mut enemy, stats) in enemy_query.iter_mut() {
+        // Splashed dogs drift with the water impulse
+        if drenched_query.contains(entity) {
+            continue;
+        }
         let direction = enemy.direction.extend(0.);
         if let Some(mut velocity) = velocity {
             velocity.linvel =
-                direction.truncate() * ENEMY_SPEED * time.delta_seconds();
+                direction.truncate() * stats.speed * time.delta_seconds();
             if velocity.angvel > 6.5 && enemy.phrase_timer.finished() {
                 message_box_request.send(MessageBoxRequest(
                     entity,
@@ -103,14 +158,107 @@ pub fn enemy_movement(
                         PhraseType::Rotation,
                     ),
                 ));
-                enemy.phrase_timer = generate_phrase_timer();
+                enemy.phrase_timer = stats.phrase_timer();
+            }
+        }
+    }
+}
+
+pub fn dry_enemies(
+    mut commands: Commands,
+    mut enemy_query: Query<(Entity, &mut Drenched)>,
+    time: Res<Time>,
+) {
+    for (entity, mut drenched) in enemy_query.iter_mut() {
+        if drenched.0.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Drenched>();
+        }
+    }
+}
+
+pub fn enemy_splash_reaction(
+    mut enemy_query: Query<(&mut Enemy, &DogStats)>,
+    mut splash_events: EventReader<DogSplashedEvent>,
+    dogs_resource: Res<DogResource>,
+    assets: Res<Assets<DogData>>,
+    mut message_box_request: EventWriter<MessageBoxRequest>,
+) {
+    for event in splash_events.iter() {
+        let Ok((mut enemy, stats)) = enemy_query.get_mut(event.dog) else {
+            continue;
+        };
+        if enemy.phrase_timer.finished() {
+            message_box_request.send(MessageBoxRequest(
+                event.dog,
+                generate_phrase(&dogs_resource, &assets, PhraseType::Splash),
+            ));
+            enemy.phrase_timer = stats.phrase_timer();
+        }
+    }
+}
+
+/// Every dog speaks up at once when a rare fish is picked, whether it's
+/// time for a phrase or not.
+pub fn enemy_rare_fish_reaction(
+    mut enemy_query: Query<(Entity, &mut Enemy, &DogStats)>,
+    mut picked_fish_events: EventReader<FishWasPickedEvent>,
+    dogs_resource: Res<DogResource>,
+    assets: Res<Assets<DogData>>,
+    mut message_box_request: EventWriter<MessageBoxRequest>,
+) {
+    let rare_picked = picked_fish_events
+        .iter()
+        .any(|event| event.kind == FishKind::Rare);
+    if !rare_picked {
+        return;
+    }
+
+    for (entity, mut enemy, stats) in enemy_query.iter_mut() {
+        message_box_request.send(MessageBoxRequest(
+            entity,
+            generate_phrase(&dogs_resource, &assets, PhraseType::RareFish),
+        ));
+        enemy.phrase_timer = stats.phrase_timer();
+    }
+}
+
+/// Turns enemy direction to the one its brain wants, wanders otherwise.
+fn steer_enemy(
+    enemy: &mut Enemy,
+    brain: &mut DogBrain,
+    position: Vec2,
+    fish: impl Iterator<Item = Vec2>,
+    kitty: Option<Vec2>,
+    delta_seconds: f32,
+) {
+    let desired = match brain.desired_direction(position, fish, kitty) {
+        Some(desired) => desired,
+        None => {
+            brain
+                .wander_timer
+                .tick(Duration::from_secs_f32(delta_seconds));
+            if brain.wander_timer.just_finished() {
+                let angle = rand::thread_rng().gen_range(-0.6..0.6);
+                enemy.direction = enemy.direction.rotated(angle);
             }
+            return;
         }
+    };
+
+    // Limit turning speed, so dogs don't jerk around
+    let angle = enemy.direction.angle_between(desired);
+    if angle.is_nan() {
+        return;
     }
+    let max_turn = brain.turn_rate * delta_seconds;
+    enemy.direction = enemy
+        .direction
+        .rotated(angle.clamp(-max_turn, max_turn))
+        .normalize_or_zero();
 }
 
 pub fn enemy_chatting(
-    mut enemy_query: Query<(Entity, &mut Enemy)>,
+    mut enemy_query: Query<(Entity, &mut Enemy, &DogStats)>,
     time: Res<Time>,
     dogs_resource: Res<DogResource>,
     assets: Res<Assets<DogData>>,
@@ -119,11 +267,11 @@ pub fn enemy_chatting(
 ) {
     let events: Vec<_> = picked_fish_events.iter().collect();
     // First iterate enemy_query, because we need to tick all their timers
-    for (entity, mut enemy) in enemy_query.iter_mut() {
+    for (entity, mut enemy, stats) in enemy_query.iter_mut() {
         if enemy.phrase_timer.tick(time.delta()).finished() {
             // Events need to not be consumed
             if let Some(event) = events.iter().next() {
-                if event.0 == entity {
+                if event.picker == entity {
                     message_box_request.send(MessageBoxRequest(
                         entity,
                         generate_phrase(
@@ -132,7 +280,7 @@ pub fn enemy_chatting(
                             PhraseType::Picking,
                         ),
                     ));
-                    enemy.phrase_timer = generate_phrase_timer();
+                    enemy.phrase_timer = stats.phrase_timer();
                 }
             }
         }
@@ -223,20 +371,23 @@ pub fn update_enemy_direction(
 
 pub fn system_add_collider_to_enemy(
     window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
     mut commands: Commands,
-    mut entity_query: Query<(Entity, &mut Enemy, &Transform)>,
+    mut entity_query: Query<(Entity, &mut Enemy, &Transform, &DogStats)>,
     mut events: EventWriter<MessageBoxRequest>,
     dogs_resource: Res<DogResource>,
     assets: Res<Assets<DogData>>,
 ) {
-    for (entity, mut enemy, transform) in entity_query.iter_mut() {
+    for (entity, mut enemy, transform, stats) in entity_query.iter_mut() {
         if !enemy.has_collider {
-            let window = window_query.single();
+            let arena = arena.rect(window_query.single());
             let size = DOG_SIZE * enemy.scale;
-            if is_in_window(window, size, transform) {
-                commands
-                    .entity(entity)
-                    .insert(Collider::ball(DOG_SIZE.x * enemy.scale * 0.47));
+            if is_in_arena(arena, size, transform) {
+                commands.entity(entity).insert((
+                    Collider::ball(DOG_SIZE.x * enemy.scale * 0.47),
+                    ColliderMassProperties::Mass(stats.mass),
+                    Restitution::coefficient(stats.restitution),
+                ));
                 enemy.has_collider = true;
 
                 // Send event
@@ -245,7 +396,7 @@ pub fn system_add_collider_to_enemy(
                     generate_phrase(
                         &dogs_resource,
                         &assets,
-                        PhraseType::Hello(enemy.dog_type),
+                        PhraseType::Hello(enemy.roster_id.clone()),
                     ),
                 ));
             }
@@ -329,43 +480,48 @@ pub fn spawn_message_box(
 pub fn spawn_enemy_on_game_progress(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
     asset_server: Res<AssetServer>,
-    mut dogs_resource: ResMut<DogResource>,
+    dogs_resource: Res<DogResource>,
     names_assets: Res<Assets<DogData>>,
-    mut already_spawned_data: Local<(HashSet<String>, u8)>,
-    mut picked_event: EventReader<ScoreUpdateEvent>,
+    mut already_spawned_data: Local<(HashSet<String>, HashSet<String>)>,
+    mut spawn_requests: EventReader<DogSpawnRequest>,
     mut arriving_event: EventWriter<EnemyIsArrivingEvent>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
 ) {
-    let event = picked_event
-        .iter()
-        .find(|event| event.name.as_str().eq("Kitty"));
-
-    // If we didn't found any Kitty in these events return
-    if let None = event {
+    // Wait until roster is loaded
+    let Some(dogs_data) = names_assets.get(&dogs_resource.json_data) else {
+        return;
+    };
+    if dogs_resource.dogs.is_empty() {
         return;
     }
 
-    let score = event.unwrap().event_type.get_score();
-
-    if score % 7 == 0 {
+    if let Some(request) = spawn_requests.iter().next() {
         let window = window_query.get_single().unwrap();
-        let center = Vec2::new(window.width() / 2., window.height() / 2.);
+        let center = arena.rect(window).center();
 
         let mut rand_point = Vec2::new_rand();
-        rand_point *= 1000.;
+        rand_point *= arena.outskirts_radius(window);
         rand_point += center;
 
         let direction = (center - rand_point).normalize();
 
-        let (name, texture, scale_modifier, dog_type, avatar) = generate_dog(
-            &mut dogs_resource,
-            names_assets,
+        let (name, dog) = generate_dog(
+            &dogs_resource,
+            dogs_data,
             &mut already_spawned_data,
+            &request.dog_types,
         );
+        let scale_modifier = dog.entry.scale;
+        let dog_type = dog.entry.dog_type;
 
+        let stats = dog_type.default_stats();
         let mut rng = rand::thread_rng();
-        let mut angvel = if rng.gen_range(0..50) > 1 { 0.3 } else { 7. };
+        let mut angvel = if rng.gen_bool(stats.wild_spin_chance) {
+            7.
+        } else {
+            rng.gen_range(stats.angvel.clone())
+        };
         angvel *= if rng.gen::<bool>() { 1. } else { -1. };
 
         let entity = commands
@@ -383,9 +539,12 @@ pub fn spawn_enemy_on_game_progress(
                     has_collider: false,
                     scale: scale_modifier,
                     dog_type,
+                    roster_id: dog.entry.id.clone(),
                     phrase_timer: Timer::from_seconds(12., TimerMode::Once),
                 },
-                avatar,
+                dog_type.default_brain(),
+                stats,
+                Avatar(dog.avatar.clone()),
                 Name::new(name.clone()),
             ))
             .with_children(|parent| {
@@ -429,6 +588,11 @@ pub fn spawn_enemy_on_game_progress(
                             PatchOfLight,
                         ));
                     });
+                parent.spawn((
+                    ParticleEmitter::new(ParticleEffectKind::Notes),
+                    SpatialBundle::default(),
+                    NoteEmitter,
+                ));
             })
             .id();
 
@@ -450,49 +614,39 @@ pub fn spawn_enemy_on_game_progress(
             });
         }
 
-        match dog_type {
-            DogType::Harry => {
-                let wand = commands
-                    .spawn(SpriteBundle {
-                        transform: Transform {
-                            translation: Vec3::new(-38.3, -42.8, -0.5),
-                            rotation: Quat::from_rotation_z(-4.2),
-                            scale: Vec3::new(0.2, 0.2, 0.),
-                            ..default()
-                        },
-                        texture: asset_server.load("sprites/Magic wand.png"),
+        // Doggy Potter never goes anywhere without his wand
+        if dog_type == DogType::Harry {
+            let wand = commands
+                .spawn(SpriteBundle {
+                    transform: Transform {
+                        translation: Vec3::new(-38.3, -42.8, -0.5),
+                        rotation: Quat::from_rotation_z(-4.2),
+                        scale: Vec3::new(0.2, 0.2, 0.),
                         ..default()
-                    })
-                    .id();
-                commands.entity(entity).push_children(&[wand]);
-                insert_ordinary_sprite(
-                    &mut commands,
-                    entity,
-                    scale_modifier,
-                    rand_point,
-                    texture,
-                );
-            }
-            DogType::BigBoy => {
-                // Load SpriteSheet
-                let texture_atlas =
-                    texture_atlases.add(TextureAtlas::from_grid(
-                        asset_server.load("sprites/Big Boy Spritesheet.png"),
-                        Vec2::new(313., 309.),
-                        4,
-                        3,
-                        None,
-                        None,
-                    ));
-                let timer = Timer::from_seconds(0.3, TimerMode::Repeating);
-                commands.entity(entity).insert(BoyAnimation(timer, 11));
+                    },
+                    texture: asset_server.load("sprites/Magic wand.png"),
+                    ..default()
+                })
+                .id();
+            commands.entity(entity).push_children(&[wand]);
+        }
+
+        match (&dog.texture_atlas, &dog.entry.spritesheet) {
+            (Some(texture_atlas), Some(sheet)) => {
+                let timer =
+                    Timer::from_seconds(sheet.frame_time, TimerMode::Repeating);
+                // Roster validation guarantees at least one frame
+                let last_frame = sheet.frame_count() - 1;
+                commands
+                    .entity(entity)
+                    .insert(SpriteAnimation(timer, last_frame));
                 commands.entity(entity).insert(SpriteSheetBundle {
                     sprite: TextureAtlasSprite {
                         custom_size: Some(DOG_SIZE * scale_modifier),
                         index: 0,
                         ..default()
                     },
-                    texture_atlas,
+                    texture_atlas: texture_atlas.clone(),
                     transform: Transform::from_xyz(
                         rand_point.x,
                         rand_point.y,
@@ -507,7 +661,7 @@ pub fn spawn_enemy_on_game_progress(
                     entity,
                     scale_modifier,
                     rand_point,
-                    texture,
+                    dog.texture.clone(),
                 );
             }
         }
@@ -516,85 +670,16 @@ pub fn spawn_enemy_on_game_progress(
 }
 
 pub fn emit_notes(
-    mut commands: Commands,
-    dogs_query: Query<&GlobalTransform, With<Enemy>>,
-    texture_storage: Res<TextureStorage>,
+    mut emitter_query: Query<&mut ParticleEmitter, With<NoteEmitter>>,
     mut doggy_theme_events: EventReader<DoggyTheme>,
 ) {
     for _ in doggy_theme_events.iter() {
-        for dog_transform in dogs_query.iter() {
-            let mut rng = rand::thread_rng();
-
-            for i in 0..3 {
-                let angle = std::f32::consts::PI * 2.0 / 3.0 * i as f32;
-                let direction = Vec2::ONE.rotated(angle);
-                let velocity = 100.;
-                let timer = Timer::from_seconds(1.5, TimerMode::Once);
-                let texture = get_random_note_texture(&texture_storage);
-
-                commands.spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::new(279., 270.) / 5.9),
-                            //
-                            ..default()
-                        },
-                        transform: Transform::from_translation(
-                            dog_transform.translation(),
-                        )
-                        .with_rotation(Quat::from_rotation_z(rng.gen())),
-                        texture,
-                        ..default()
-                    },
-                    NoteParticle {
-                        direction,
-                        velocity,
-                        timer,
-                    },
-                ));
-            }
-        }
-    }
-}
-
-pub fn poll_and_despawn_notes(
-    mut commands: Commands,
-    mut particles_query: Query<(
-        Entity,
-        &mut Sprite,
-        &mut Transform,
-        &mut NoteParticle,
-    )>,
-    time: Res<Time>,
-) {
-    for (entity, mut sprite, mut transform, mut particle) in
-        particles_query.iter_mut()
-    {
-        if particle.timer.tick(time.delta()).finished() {
-            commands.entity(entity).despawn();
-        } else {
-            let x =
-                particle.direction.x * time.delta_seconds() * particle.velocity;
-            let y =
-                particle.direction.y * time.delta_seconds() * particle.velocity;
-
-            transform.translation.x += x;
-            transform.translation.y += y;
-            sprite.color.set_a(particle.timer.percent_left());
-            particle.velocity = particle.velocity - time.delta_seconds() * 87.;
+        for mut emitter in emitter_query.iter_mut() {
+            emitter.burst += 3;
         }
     }
 }
 
-pub fn despawn_notes_on_exit(
-    mut commands: Commands,
-    notes_query: Query<Entity, With<NoteParticle>>,
-) {
-    for entity in notes_query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
-
 pub fn rotate_patch_of_light(
     mut patch_query: Query<(Entity, &mut Transform), With<PatchOfLight>>,
     parents_query: Query<&Parent>,
@@ -674,16 +759,16 @@ pub fn update_message_box(
     }
 }
 
-pub fn animate_big_boy(
-    mut big_boy_query: Query<
-        (&mut TextureAtlasSprite, &mut BoyAnimation),
+pub fn animate_dog_sprites(
+    mut animated_query: Query<
+        (&mut TextureAtlasSprite, &mut SpriteAnimation),
         With<Enemy>,
     >,
     time: Res<Time>,
 ) {
-    for (mut atlas, mut animation) in big_boy_query.iter_mut() {
+    for (mut atlas, mut animation) in animated_query.iter_mut() {
         if animation.0.tick(time.delta()).finished() {
-            atlas.index = if atlas.index == animation.1 as usize {
+            atlas.index = if atlas.index >= animation.1 {
                 0
             } else {
                 atlas.index + 1
@@ -692,57 +777,43 @@ pub fn animate_big_boy(
     }
 }
 
-fn generate_dog(
-    dogs_resource: &mut ResMut<DogResource>,
-    assets: Res<Assets<DogData>>,
-    already_spawned_data: &mut Local<(HashSet<String>, u8)>,
-) -> (String, Handle<Image>, f32, DogType, Avatar) {
+fn generate_dog<'a>(
+    dogs_resource: &'a DogResource,
+    dogs_data: &DogData,
+    already_spawned_data: &mut (HashSet<String>, HashSet<String>),
+    dog_types: &[DogType],
+) -> (String, &'a OneDog) {
     // Rand
     let mut rng = rand::thread_rng();
     let last_name_possibility = rng.gen::<bool>();
     let nickname_possibility = rng.gen::<bool>() && last_name_possibility;
 
-    // Get handles
-    let dogs_data = assets.get(&dogs_resource.json_data).unwrap();
-    let id = dogs_resource.dogs[already_spawned_data.1 as usize]
-        .texture_identifier
-        .clone();
-    let image = dogs_resource.dogs[already_spawned_data.1 as usize]
-        .texture
-        .clone();
-    let avatar = Avatar(
-        dogs_resource.dogs[already_spawned_data.1 as usize]
-            .avatar
-            .clone(),
-    );
-
-    {
-        if already_spawned_data.1 < 11 {
-            already_spawned_data.1 += 1;
-        } else {
-            already_spawned_data.1 = 0;
-            // Shuffle sprites on next round of spawning
-            dogs_resource.dogs.shuffle(&mut rng);
-        }
+    // Every eligible dog arrives once per round, chosen by spawn weight
+    let is_eligible = |dog: &&OneDog| {
+        dog_types.is_empty() || dog_types.contains(&dog.entry.dog_type)
+    };
+    let mut candidates: Vec<&OneDog> = dogs_resource
+        .dogs
+        .iter()
+        .filter(is_eligible)
+        .filter(|dog| !already_spawned_data.1.contains(&dog.entry.id))
+        .collect();
+    if candidates.is_empty() {
+        // Start a new round
+        already_spawned_data.1.clear();
+        candidates = dogs_resource.dogs.iter().filter(is_eligible).collect();
     }
-    let mut default_scale = 0.5;
-    let mut default_dog_type = DogType::Ordinary;
-
-    // Handle Doggy Potter case
-    if &id == "FaceHarry" {
-        return (
-            String::from("Doggy Potter"),
-            image,
-            default_scale,
-            DogType::Harry,
-            avatar,
-        );
+    if candidates.is_empty() {
+        // No dogs of these types in the roster
+        candidates = dogs_resource.dogs.iter().collect();
     }
+    let dog = *candidates
+        .choose_weighted(&mut rng, |dog| dog.entry.spawn_weight)
+        .unwrap_or(&candidates[0]);
+    already_spawned_data.1.insert(dog.entry.id.clone());
 
-    // Handle Big Kid case
-    if &id == "FaceBigBoy" {
-        default_scale = 1.0;
-        default_dog_type = DogType::BigBoy;
+    if let Some(name) = &dog.entry.name {
+        return (name.clone(), dog);
     }
 
     // Generate name
@@ -771,16 +842,178 @@ fn generate_dog(
         }
     }
 
-    (name, image, default_scale, default_dog_type, avatar)
+    (name, dog)
 }
 
-fn is_in_window(window: &Window, size: Vec2, transform: &Transform) -> bool {
+type LocatorCameraQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Transform, &'static OrthographicProjection),
+    (With<Camera2d>, Without<DogLocator>),
+>;
+
+/// Keeps a locator at the screen edge for every dog out of camera view.
+pub fn update_dog_locators(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    enemy_query: Query<(Entity, &Transform, &Avatar), With<Enemy>>,
+    camera_query: LocatorCameraQuery,
+    mut locator_query: Query<
+        (Entity, &mut DogLocator, &mut Transform),
+        Without<Enemy>,
+    >,
+) {
+    // Camera has just moved this frame, so its global transform is stale
+    let Ok((cam_transform, cam_projection)) = camera_query.get_single() else {
+        return;
+    };
+    let (left, right, top, bottom) =
+        get_camera_borders(cam_transform, cam_projection.area);
+    let view = Rect::new(left.x, bottom.y, right.x, top.y);
+
+    let mut placements: HashMap<Entity, (Vec2, Vec2, f32)> = enemy_query
+        .iter()
+        .filter_map(|(entity, transform, _)| {
+            locator_placement(view, transform.translation.truncate())
+                .map(|placement| (entity, placement))
+        })
+        .collect();
+
+    // Move existing locators, remove ones which dog is in view or gone
+    for (entity, mut locator, mut transform) in locator_query.iter_mut() {
+        match placements.remove(&locator.dog) {
+            Some((position, direction, distance)) => {
+                transform.translation =
+                    position.extend(transform.translation.z);
+                locator.direction = direction;
+                locator.distance = distance;
+            }
+            None => commands.entity(entity).despawn_recursive(),
+        }
+    }
+
+    // Dogs left in placements have no locator yet
+    for (dog, (position, direction, distance)) in placements {
+        let Ok((_, _, avatar)) = enemy_query.get(dog) else {
+            continue;
+        };
+        commands
+            .spawn((
+                SpatialBundle::from_transform(Transform::from_translation(
+                    position.extend(10.),
+                )),
+                DogLocator {
+                    dog,
+                    direction,
+                    distance,
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::splat(LOCATOR_AVATAR_SIZE)),
+                        ..default()
+                    },
+                    texture: avatar.0.clone(),
+                    ..default()
+                });
+                parent.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::splat(24.)),
+                            color: Color::rgba(1., 1., 1., 0.8),
+                            ..default()
+                        },
+                        texture: asset_server.load("sprites/Locator arrow.png"),
+                        ..default()
+                    },
+                    LocatorMark::Arrow,
+                ));
+                parent.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_server
+                                    .load("fonts/FiraSans-Bold.ttf"),
+                                font_size: 18.,
+                                color: Color::WHITE,
+                            },
+                        )
+                        .with_alignment(TextAlignment::Center),
+                        ..default()
+                    },
+                    LocatorMark::Distance,
+                ));
+            });
+    }
+}
+
+/// Points locator arrows to their dogs and refreshes distance labels.
+pub fn update_dog_locator_marks(
+    locator_query: Query<&DogLocator>,
+    mut mark_query: Query<(
+        &LocatorMark,
+        &Parent,
+        &mut Transform,
+        Option<&mut Text>,
+    )>,
+) {
+    for (mark, parent, mut transform, text) in mark_query.iter_mut() {
+        let Ok(locator) = locator_query.get(parent.get()) else {
+            continue;
+        };
+        match mark {
+            LocatorMark::Arrow => {
+                transform.translation =
+                    (locator.direction * LOCATOR_ARROW_OFFSET).extend(1.);
+                transform.rotation = Quat::from_rotation_z(
+                    locator.direction.y.atan2(locator.direction.x),
+                );
+            }
+            LocatorMark::Distance => {
+                // Label goes on the opposite side of the arrow
+                transform.translation =
+                    (-locator.direction * LOCATOR_ARROW_OFFSET).extend(1.);
+                if let Some(mut text) = text {
+                    text.sections[0].value = format!("{:.0}", locator.distance);
+                }
+            }
+        }
+    }
+}
+
+pub fn despawn_dog_locators(
+    mut commands: Commands,
+    locator_query: Query<Entity, With<DogLocator>>,
+) {
+    for entity in locator_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Returns locator position inside of `view`, direction and distance to
+/// the dog, `None` if the dog is in view.
+fn locator_placement(view: Rect, dog: Vec2) -> Option<(Vec2, Vec2, f32)> {
+    if view.contains(dog) {
+        return None;
+    }
+    let inner = Rect::from_corners(
+        view.min + Vec2::splat(LOCATOR_MARGIN),
+        view.max - Vec2::splat(LOCATOR_MARGIN),
+    );
+    let position = dog.clamp(inner.min, inner.max);
+    let to_dog = dog - position;
+    Some((position, to_dog.normalize_or_zero(), to_dog.length()))
+}
+
+fn is_in_arena(arena: Rect, size: Vec2, transform: &Transform) -> bool {
     let pos = transform.translation.truncate();
     let size = size + Vec2::new(2., 2.);
-    pos.x - size.x > 0.0
-        && pos.x + size.x < window.width()
-        && pos.y - size.y > 0.0
-        && pos.y + size.y < window.height()
+    pos.x - size.x > arena.min.x
+        && pos.x + size.x < arena.max.x
+        && pos.y - size.y > arena.min.y
+        && pos.y + size.y < arena.max.y
 }
 
 fn get_random_bark<'a>(
@@ -813,10 +1046,8 @@ fn generate_phrase(
 
     // Get handles
     let vec = match phrs_type {
-        PhraseType::Hello(dog_type) => match dog_type {
-            DogType::Harry => {
-                &assets.get(&dogs_resource.json_data).unwrap().potters_hellos
-            }
+        PhraseType::Hello(id) => match dogs_resource.get(&id) {
+            Some(dog) if !dog.entry.hellos.is_empty() => &dog.entry.hellos,
             _ => &assets.get(&dogs_resource.json_data).unwrap().hellos,
         },
         PhraseType::Rotation => {
@@ -828,29 +1059,13 @@ fn generate_phrase(
         PhraseType::Picking => {
             &assets.get(&dogs_resource.json_data).unwrap().fish_picking
         }
+        PhraseType::Splash => {
+            &assets.get(&dogs_resource.json_data).unwrap().getting_wet
+        }
+        PhraseType::RareFish => {
+            &assets.get(&dogs_resource.json_data).unwrap().rare_fish
+        }
     };
 
     vec.choose(&mut rand).unwrap_or(&String::new()).clone()
 }
-
-fn generate_phrase_timer() -> Timer {
-    let rand = rand::thread_rng().gen_range(3.0..10.0);
-    Timer::from_seconds(rand, TimerMode::Once)
-}
-
-fn get_random_note_texture(
-    texture_storage: &Res<TextureStorage>,
-) -> Handle<Image> {
-    let idx = rand::thread_rng().gen_range(0..7);
-
-    match idx {
-        0 => texture_storage.note1.clone_weak(),
-        1 => texture_storage.note2.clone_weak(),
-        2 => texture_storage.note3.clone_weak(),
-        3 => texture_storage.note4.clone_weak(),
-        4 => texture_storage.note5.clone_weak(),
-        5 => texture_storage.note6.clone_weak(),
-        6 => texture_storage.note7.clone_weak(),
-        _ => texture_storage.note8.clone_weak(),
-    }
-}
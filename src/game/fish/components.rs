@@ -1,10 +1,85 @@
 use bevy::prelude::*;
 
 #[derive(Component, Reflect)]
-pub struct Fish {}
+pub struct Fish {
+    pub kind: FishKind,
+}
+
+/// What a fish is worth and how it looks and sounds.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect, FromReflect)]
+pub enum FishKind {
+    Common,
+    /// Worth several crackers.
+    Golden,
+    /// Takes crackers away from whoever eats it.
+    Rotten,
+    /// Makes every dog around speak up at once.
+    Rare,
+}
+
+impl FishKind {
+    pub const ALL: [FishKind; 4] = [
+        FishKind::Common,
+        FishKind::Golden,
+        FishKind::Rotten,
+        FishKind::Rare,
+    ];
+
+    /// Crackers added to the score of whoever picks the fish up.
+    pub fn value(&self) -> i32 {
+        match self {
+            FishKind::Common => 1,
+            FishKind::Golden => 5,
+            FishKind::Rotten => -3,
+            FishKind::Rare => 2,
+        }
+    }
+
+    /// Relative chance to be spawned.
+    pub fn spawn_weight(&self) -> u32 {
+        match self {
+            FishKind::Common => 75,
+            FishKind::Golden => 8,
+            FishKind::Rotten => 12,
+            FishKind::Rare => 5,
+        }
+    }
+
+    pub fn texture_path(&self) -> &'static str {
+        match self {
+            FishKind::Common => "sprites/Fish.png",
+            FishKind::Golden => "sprites/Fish golden.png",
+            FishKind::Rotten => "sprites/Fish rotten.png",
+            FishKind::Rare => "sprites/Fish rare.png",
+        }
+    }
+}
 
 #[derive(Component)]
 pub struct FishPack;
 
+/// Fish which swims with its school instead of wobbling in place.
+#[derive(Component)]
+pub struct SchoolingFish {
+    pub velocity: Vec2,
+}
+
+/// Fish knocked out of Kitty on hit. Flies away and can be picked up again
+/// until `lifetime` runs out.
+#[derive(Component)]
+pub struct DroppedFish {
+    pub kind: FishKind,
+    pub direction: Vec2,
+    pub velocity: f32,
+    pub lifetime: Timer,
+    /// `Fish` and collider are attached once it is finished, so Kitty
+    /// doesn't catch fish back right at the moment of hit.
+    pub pickup_delay: Timer,
+}
+
 // Event
-pub struct FishWasPickedEvent(pub Entity);
+pub struct FishWasPickedEvent {
+    /// Kitty or dog who picked the fish up.
+    pub picker: Entity,
+    pub kind: FishKind,
+}
@@ -3,11 +3,11 @@ use bevy::prelude::*;
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use self::{
-    components::{Fish, FishWasPickedEvent},
+    components::{Fish, FishKind, FishWasPickedEvent},
     resources::*,
     systems::*,
 };
-use super::SimulationState;
+use super::{player::systems::spawn_player, SimulationState};
 use crate::AppState;
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
@@ -20,6 +20,10 @@ pub mod systems;
 
 const NUMBER_OF_FISH: usize = 10;
 pub const FISH_SIZE: Vec2 = Vec2::new(168. / 5.9, 130. / 5.9);
+const DROPPED_FISH_LIFETIME: f32 = 6.;
+const DROPPED_FISH_FADE_TIME: f32 = 2.;
+const DROPPED_FISH_PICKUP_DELAY: f32 = 0.4;
+const FISH_START_SPEED: f32 = 40.;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -30,19 +34,38 @@ impl Plugin for FishPlugin {
         app
             // Register types
             .register_type::<Fish>()
+            .register_type::<FishKind>()
             // Resources
-            .init_resource::<FishSpawnTimer>()
+            .init_resource::<FishSpawnDirector>()
+            .init_resource::<FishMovement>()
+            .init_resource::<SchoolingRules>()
             // Events
             .add_event::<FishWasPickedEvent>()
             // Enter State Systems
-            .add_system(spawn_fish.in_schedule(OnEnter(AppState::Game)))
+            // Ships have to exist to keep fish away from them
+            .add_systems(
+                (apply_system_buffers, spawn_fish)
+                    .chain()
+                    .after(spawn_player)
+                    .in_schedule(OnEnter(AppState::Game)),
+            )
             // Systems
             .add_systems(
-                (tick_fish_spawn_timer, spawn_fish_over_time, check_collision)
+                (
+                    tick_fish_spawn_director,
+                    spawn_fish_over_time,
+                    check_collision,
+                    spawn_dropped_fish,
+                    move_dropped_fish,
+                    school_fish,
+                )
                     .in_set(OnUpdate(AppState::Game))
                     .in_set(OnUpdate(SimulationState::Running)),
             )
             // Exit State Systems
-            .add_system(despawn_fish.in_schedule(OnExit(AppState::Game)));
+            .add_systems(
+                (despawn_fish, despawn_dropped_fish)
+                    .in_schedule(OnExit(AppState::Game)),
+            );
     }
 }
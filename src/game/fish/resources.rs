@@ -1,20 +1,267 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use rand::Rng;
 
 // ───── Constants ────────────────────────────────────────────────────────── //
 
-const SPAWN_STAR_TIMER: f32 = 1.;
+const FISH_CAP: usize = 20;
+const FISH_SPACING: f32 = 70.;
+const SHIP_CLEARANCE: f32 = 160.;
+const FISH_PER_COLLECTOR: f32 = 0.5;
+/// Candidates tried for every new fish before the area counts as full, as
+/// in Bridson's Poisson-disk sampling.
+const SPAWN_ATTEMPTS: usize = 30;
+
+const SCHOOL_RADIUS: f32 = 130.;
+const SEPARATION_RADIUS: f32 = 45.;
+const FLEE_RADIUS: f32 = 190.;
+const SCHOOL_MIN_SPEED: f32 = 25.;
+const SCHOOL_MAX_SPEED: f32 = 150.;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
+/// Decides how often and where new fish appear.
 #[derive(Resource)]
-pub struct FishSpawnTimer {
+pub struct FishSpawnDirector {
+    /// Most fish which may float around at once.
+    pub cap: usize,
+    /// New fish never appear closer than this to other fish.
+    pub min_spacing: f32,
+    /// New fish never appear closer than this to Kitty or dogs.
+    pub ship_clearance: f32,
+    /// Fish per second for every Kitty and dog collecting them.
+    pub rate_per_collector: f32,
     pub timer: Timer,
 }
 
-impl Default for FishSpawnTimer {
+impl Default for FishSpawnDirector {
+    fn default() -> Self {
+        FishSpawnDirector {
+            cap: FISH_CAP,
+            min_spacing: FISH_SPACING,
+            ship_clearance: SHIP_CLEARANCE,
+            rate_per_collector: FISH_PER_COLLECTOR,
+            timer: Timer::from_seconds(1., TimerMode::Repeating),
+        }
+    }
+}
+
+impl FishSpawnDirector {
+    /// Speeds spawning up or slows it down to feed all `collectors`.
+    pub fn set_collectors(&mut self, collectors: usize) {
+        let rate = self.rate_per_collector * collectors as f32;
+        if rate <= 0. {
+            return;
+        }
+
+        let period = Duration::from_secs_f32(1. / rate);
+        if self.timer.duration() != period {
+            self.timer.set_duration(period);
+        }
+    }
+
+    /// Random point of `area` which keeps the spacing from `fish` and the
+    /// clearance from `ships`. Gives up after a few attempts, so a crowded
+    /// area just gets no new fish.
+    pub fn pick_position(
+        &self,
+        area: Rect,
+        fish: &[Vec2],
+        ships: &[Vec2],
+        rng: &mut impl Rng,
+    ) -> Option<Vec2> {
+        (0..SPAWN_ATTEMPTS)
+            .map(|_| {
+                Vec2::new(
+                    rng.gen_range(area.min.x..=area.max.x),
+                    rng.gen_range(area.min.y..=area.max.y),
+                )
+            })
+            .find(|candidate| {
+                fish.iter()
+                    .all(|fish| fish.distance(*candidate) >= self.min_spacing)
+                    && ships.iter().all(|ship| {
+                        ship.distance(*candidate) >= self.ship_clearance
+                    })
+            })
+    }
+}
+
+/// How fish move between being spawned and picked up, chosen in the main
+/// menu.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FishMovement {
+    /// Every fish wobbles around its spawn point.
+    #[default]
+    Wobble,
+    /// Fish swim in schools and flee from ships.
+    Schooling,
+}
+
+impl FishMovement {
+    pub fn toggled(&self) -> FishMovement {
+        match self {
+            FishMovement::Wobble => FishMovement::Schooling,
+            FishMovement::Schooling => FishMovement::Wobble,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FishMovement::Wobble => "Lazy fish",
+            FishMovement::Schooling => "Schooling fish",
+        }
+    }
+}
+
+/// Boids rules of schooling fish. Weights are accelerations applied by
+/// every rule at full strength.
+#[derive(Resource)]
+pub struct SchoolingRules {
+    /// Fish closer than this belong to the same school.
+    pub school_radius: f32,
+    /// Fish closer than this push each other apart.
+    pub separation_radius: f32,
+    /// Fish flee from ships closer than this.
+    pub flee_radius: f32,
+    pub cohesion: f32,
+    pub alignment: f32,
+    pub separation: f32,
+    pub flee: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+}
+
+impl Default for SchoolingRules {
     fn default() -> Self {
-        FishSpawnTimer {
-            timer: Timer::from_seconds(SPAWN_STAR_TIMER, TimerMode::Repeating),
+        SchoolingRules {
+            school_radius: SCHOOL_RADIUS,
+            separation_radius: SEPARATION_RADIUS,
+            flee_radius: FLEE_RADIUS,
+            cohesion: 30.,
+            alignment: 60.,
+            separation: 180.,
+            flee: 600.,
+            min_speed: SCHOOL_MIN_SPEED,
+            max_speed: SCHOOL_MAX_SPEED,
+        }
+    }
+}
+
+impl SchoolingRules {
+    /// Acceleration of the fish at `position` from its `school`, which are
+    /// positions and velocities of other fish, and from nearby `ships`.
+    pub fn acceleration(
+        &self,
+        position: Vec2,
+        velocity: Vec2,
+        school: impl Iterator<Item = (Vec2, Vec2)>,
+        ships: impl Iterator<Item = Vec2>,
+    ) -> Vec2 {
+        let mut center = Vec2::ZERO;
+        let mut heading = Vec2::ZERO;
+        let mut push = Vec2::ZERO;
+        let mut mates = 0;
+        for (other, other_velocity) in school {
+            let offset = position - other;
+            let distance = offset.length();
+            if distance > self.school_radius || distance == 0. {
+                continue;
+            }
+
+            center += other;
+            heading += other_velocity;
+            mates += 1;
+            if distance < self.separation_radius {
+                push += offset / distance
+                    * (1. - distance / self.separation_radius);
+            }
         }
+
+        let mut acceleration = push * self.separation;
+        if mates > 0 {
+            let center = center / mates as f32;
+            let heading = heading / mates as f32;
+            acceleration += (center - position)
+                .clamp_length_max(self.school_radius)
+                / self.school_radius
+                * self.cohesion;
+            acceleration += (heading - velocity)
+                .clamp_length_max(self.max_speed)
+                / self.max_speed
+                * self.alignment;
+        }
+
+        for ship in ships {
+            let offset = position - ship;
+            let distance = offset.length();
+            if distance < self.flee_radius && distance > 0. {
+                acceleration += offset / distance
+                    * (1. - distance / self.flee_radius)
+                    * self.flee;
+            }
+        }
+
+        acceleration
+    }
+
+    pub fn clamp_speed(&self, velocity: Vec2) -> Vec2 {
+        let speed = velocity.length();
+        if speed < f32::EPSILON {
+            Vec2::X * self.min_speed
+        } else {
+            velocity / speed * speed.clamp(self.min_speed, self.max_speed)
+        }
+    }
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picked_positions_keep_their_distance() {
+        let director = FishSpawnDirector::default();
+        let area = Rect::new(0., 0., 800., 600.);
+        let ships = [Vec2::new(400., 300.)];
+        let mut rng = rand::thread_rng();
+
+        let mut fish = Vec::new();
+        while let Some(position) =
+            director.pick_position(area, &fish, &ships, &mut rng)
+        {
+            assert!(area.contains(position));
+            assert!(position.distance(ships[0]) >= director.ship_clearance);
+            assert!(fish
+                .iter()
+                .all(|f: &Vec2| f.distance(position) >= director.min_spacing));
+            fish.push(position);
+        }
+        assert!(!fish.is_empty());
+    }
+
+    #[test]
+    fn schooling_fish_flee_from_ships() {
+        let rules = SchoolingRules::default();
+        let ship = Vec2::new(100., 0.);
+        let acceleration = rules.acceleration(
+            Vec2::ZERO,
+            Vec2::X * rules.min_speed,
+            std::iter::empty(),
+            std::iter::once(ship),
+        );
+        assert!(acceleration.x < 0.);
+
+        // Far ships are ignored
+        let acceleration = rules.acceleration(
+            Vec2::ZERO,
+            Vec2::X * rules.min_speed,
+            std::iter::empty(),
+            std::iter::once(Vec2::X * rules.flee_radius * 2.),
+        );
+        assert_eq!(acceleration, Vec2::ZERO);
     }
 }
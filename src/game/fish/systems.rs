@@ -1,4 +1,4 @@
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::{ecs::system::SystemParam, prelude::*, window::PrimaryWindow};
 use bevy_rapier2d::prelude::*;
 use bevy_tweening::{lens::*, *};
 use kira::sound::static_sound::StaticSoundSettings;
@@ -8,54 +8,85 @@ use rand::prelude::*;
 
 use crate::audio::assets::AudioSource;
 use crate::audio::resources::{KiraManager, SamplePack};
-use crate::game::{enemy::components::Enemy, player::components::Player};
+use crate::events::PlayerHit;
+use crate::game::enemy::components::Enemy;
+use crate::game::player::components::{PickupArea, Player};
+use crate::game::resources::Arena;
+use crate::helper_functions::VectorUtilities;
 
 use super::{
-    components::*, resources::FishSpawnTimer, FISH_SIZE, NUMBER_OF_FISH,
+    components::*,
+    resources::{FishMovement, FishSpawnDirector, SchoolingRules},
+    DROPPED_FISH_FADE_TIME, DROPPED_FISH_LIFETIME, DROPPED_FISH_PICKUP_DELAY,
+    FISH_SIZE, FISH_START_SPEED, NUMBER_OF_FISH,
 };
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
+/// Kitties and dogs, fish keep clear of their ships.
+type ShipQuery<'w, 's, T> = Query<'w, 's, T, Or<(With<Player>, With<Enemy>)>>;
+
+/// Room for new fish in the arena, away from ships, and the director which
+/// paces them.
+#[derive(SystemParam)]
+pub struct FishSpawnSpace<'w, 's> {
+    window_query: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    ships_query: ShipQuery<'w, 's, &'static GlobalTransform>,
+    arena: Res<'w, Arena>,
+    director: Res<'w, FishSpawnDirector>,
+}
+
+impl FishSpawnSpace<'_, '_> {
+    fn area(&self) -> Rect {
+        spawn_area(self.arena.rect(self.window_query.get_single().unwrap()))
+    }
+
+    fn ships(&self) -> Vec<Vec2> {
+        self.ships_query
+            .iter()
+            .map(|transform| transform.translation().truncate())
+            .collect()
+    }
+}
+
 pub fn spawn_fish(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    ships_query: ShipQuery<&Transform>,
     asset_server: Res<AssetServer>,
+    arena: Res<Arena>,
+    director: Res<FishSpawnDirector>,
+    movement: Res<FishMovement>,
 ) {
-    let window = window_query.get_single().unwrap();
+    let area = spawn_area(arena.rect(window_query.get_single().unwrap()));
+    let ships: Vec<_> = ships_query
+        .iter()
+        .map(|transform| transform.translation.truncate())
+        .collect();
 
     // Entity for storing stars
     let fish = SpatialBundle::default();
     let mut children_fish = vec![];
 
+    // Every fish is placed with regard to previous ones, so the pack is
+    // spread evenly
+    let mut positions = vec![];
     let mut rand = thread_rng();
-    for _ in 0..NUMBER_OF_FISH {
-        let rand_x = rand.gen::<f32>() * window.width();
-        let rand_y = rand.gen::<f32>() * window.height();
-
-        children_fish.push(
-            commands
-                .spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            custom_size: Some(FISH_SIZE),
-                            ..default()
-                        },
-                        transform: Transform::from_xyz(rand_x, rand_y, 1.)
-                            .with_rotation(Quat::from_rotation_z(
-                                rand.gen_range(0.0..std::f32::consts::PI * 2.),
-                            )),
-                        texture: asset_server.load("sprites/Fish.png"),
-                        ..default()
-                    },
-                    Collider::ball(FISH_SIZE.x / 2.),
-                    Sensor::default(),
-                    Fish {},
-                    Animator::new(get_fish_tween(Vec3::new(
-                        rand_x, rand_y, 1.,
-                    ))),
-                ))
-                .id(),
-        );
+    for _ in 0..NUMBER_OF_FISH.min(director.cap) {
+        let Some(position) =
+            director.pick_position(area, &positions, &ships, &mut rand)
+        else {
+            break;
+        };
+        positions.push(position);
+
+        children_fish.push(spawn_floating_fish(
+            &mut commands,
+            &asset_server,
+            random_fish_kind(),
+            *movement,
+            position.extend(1.),
+        ));
     }
 
     // Save all stars in stars entity
@@ -66,6 +97,62 @@ pub fn spawn_fish(
         .push_children(&children_fish);
 }
 
+/// Part of the arena where fish don't touch the walls.
+fn spawn_area(arena: Rect) -> Rect {
+    let margin = FISH_SIZE.max_element();
+    Rect::from_corners(
+        arena.min + Vec2::splat(margin),
+        arena.max - Vec2::splat(margin),
+    )
+}
+
+/// Spawns a fish which wobbles around `position` or swims off with its
+/// school, depending on `movement`.
+fn spawn_floating_fish(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    kind: FishKind,
+    movement: FishMovement,
+    position: Vec3,
+) -> Entity {
+    let mut fish = commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(FISH_SIZE),
+                ..default()
+            },
+            transform: Transform::from_translation(position).with_rotation(
+                Quat::from_rotation_z(
+                    thread_rng().gen_range(0.0..std::f32::consts::PI * 2.),
+                ),
+            ),
+            texture: asset_server.load(kind.texture_path()),
+            ..default()
+        },
+        Collider::ball(FISH_SIZE.x / 2.),
+        Sensor::default(),
+        Fish { kind },
+    ));
+    match movement {
+        FishMovement::Wobble => {
+            fish.insert(Animator::new(get_fish_tween(position)));
+        }
+        FishMovement::Schooling => {
+            fish.insert(SchoolingFish {
+                velocity: Vec2::new_rand() * FISH_START_SPEED,
+            });
+        }
+    }
+    fish.id()
+}
+
+/// Kind of a new fish, chosen by `FishKind::spawn_weight`.
+fn random_fish_kind() -> FishKind {
+    *FishKind::ALL
+        .choose_weighted(&mut thread_rng(), FishKind::spawn_weight)
+        .unwrap()
+}
+
 pub fn despawn_fish(
     mut commands: Commands,
     star_pack_query: Query<Entity, With<FishPack>>,
@@ -75,18 +162,23 @@ pub fn despawn_fish(
         .despawn_recursive();
 }
 
-pub fn tick_fish_spawn_timer(
-    mut star_spawn_timer: ResMut<FishSpawnTimer>,
+/// Every Kitty and dog is a collector, the more of them are around the
+/// faster fish appear.
+pub fn tick_fish_spawn_director(
+    mut director: ResMut<FishSpawnDirector>,
+    collectors_query: ShipQuery<()>,
     time: Res<Time>,
 ) {
-    star_spawn_timer.timer.tick(time.delta());
+    director.set_collectors(collectors_query.iter().count());
+    director.timer.tick(time.delta());
 }
 
 pub fn check_collision(
     mut collision_events: EventReader<CollisionEvent>,
     mut commands: Commands,
-    entity_query: Query<Entity, Or<(With<Player>, With<Enemy>)>>,
-    mut fish_query: Query<(Entity, &Parent), With<Fish>>,
+    entity_query: Query<Entity, Or<(With<PickupArea>, With<Enemy>)>>,
+    pickup_area_query: Query<&Parent, With<PickupArea>>,
+    mut fish_query: Query<(Entity, &Fish, Option<&Parent>)>,
     mut kira_manager: NonSendMut<KiraManager>,
     audio_assets: Res<Assets<AudioSource>>,
     sample_pack: Res<SamplePack>,
@@ -94,65 +186,38 @@ pub fn check_collision(
 ) {
     'outer: for event in collision_events.iter() {
         if let CollisionEvent::Started(entity1, entity2, _) = event {
-            for (fish_entity, fish_pack) in fish_query.iter_mut() {
-                if fish_entity == *entity1 {
-                    for entity in entity_query.iter() {
-                        if entity == *entity2 {
-                            commands
-                                .entity(fish_pack.get())
-                                .remove_children(&[fish_entity]);
-
-                            let handle =
-                                get_random_pick_fish_sample(&sample_pack);
-                            // Play audio
-                            let sound_data = audio_assets
-                                .get(handle)
-                                .unwrap()
-                                .get()
-                                .with_settings(
-                                    StaticSoundSettings::new()
-                                        .output_destination(
-                                            kira_manager.get_master(),
-                                        ),
-                                );
-                            kira_manager
-                                .play(sound_data)
-                                .unwrap()
-                                .set_volume(0.3, kira::tween::Tween::default())
-                                .unwrap();
-
-                            commands.entity(fish_entity).despawn();
-                            picked_event.send(FishWasPickedEvent(entity));
-
-                            // Continue cycle if collision is resolved
-                            continue 'outer;
-                        }
-                    }
+            for (fish_entity, fish, fish_pack) in fish_query.iter_mut() {
+                let other = if fish_entity == *entity1 {
+                    *entity2
                 } else if fish_entity == *entity2 {
-                    for entity in entity_query.iter() {
-                        if entity == *entity1 {
+                    *entity1
+                } else {
+                    continue;
+                };
+
+                for entity in entity_query.iter() {
+                    if entity == other {
+                        if let Some(fish_pack) = fish_pack {
                             commands
                                 .entity(fish_pack.get())
                                 .remove_children(&[fish_entity]);
-                            // Play audio
-                            let sound_data = audio_assets
-                                .get(&sample_pack.pick_fish1)
-                                .unwrap()
-                                .get()
-                                .with_settings(
-                                    StaticSoundSettings::new()
-                                        .output_destination(
-                                            kira_manager.get_master(),
-                                        ),
-                                );
-                            kira_manager.play(sound_data).unwrap().set_volume(0.3, kira::tween::Tween::default())
-                            .unwrap();;
-
-                            commands.entity(fish_entity).despawn();
-                            picked_event.send(FishWasPickedEvent(entity));
-                            // Continue cycle if collision is resolved
-                            continue 'outer;
                         }
+
+                        play_pick_sound(
+                            fish.kind,
+                            &mut kira_manager,
+                            &audio_assets,
+                            &sample_pack,
+                        );
+
+                        commands.entity(fish_entity).despawn();
+                        picked_event.send(FishWasPickedEvent {
+                            picker: picker(&pickup_area_query, entity),
+                            kind: fish.kind,
+                        });
+
+                        // Continue cycle if collision is resolved
+                        continue 'outer;
                     }
                 }
             }
@@ -160,52 +225,250 @@ pub fn check_collision(
     }
 }
 
+/// Crunch of a cracker for common fish. Golden fish taste like a glass of
+/// milk, rotten ones thud and rare ones are greeted with a roar.
+fn play_pick_sound(
+    kind: FishKind,
+    kira_manager: &mut KiraManager,
+    audio_assets: &Assets<AudioSource>,
+    sample_pack: &SamplePack,
+) {
+    let handle = match kind {
+        FishKind::Common => get_random_pick_fish_sample(sample_pack),
+        FishKind::Golden => &sample_pack.milk,
+        FishKind::Rotten => &sample_pack.wall_collision,
+        FishKind::Rare => &sample_pack.meowroar,
+    };
+    let sound_data = audio_assets.get(handle).unwrap().get().with_settings(
+        StaticSoundSettings::new()
+            .output_destination(kira_manager.get_master()),
+    );
+    kira_manager
+        .play(sound_data)
+        .unwrap()
+        .set_volume(0.3, kira::tween::Tween::default())
+        .unwrap();
+}
+
 pub fn spawn_fish_over_time(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
     stars_pack_query: Query<Entity, With<FishPack>>,
+    fish_query: Query<&GlobalTransform, With<Fish>>,
     enemy_query: Query<Entity, With<Enemy>>,
     asset_server: Res<AssetServer>,
-    star_spawn_timer: Res<FishSpawnTimer>,
+    space: FishSpawnSpace,
+    movement: Res<FishMovement>,
+) {
+    // Without dogs Kitty eats the starting fish alone
+    let is_there_any_enemies = enemy_query.iter().next().is_some();
+    if !is_there_any_enemies {
+        return;
+    }
+
+    let area = space.area();
+    let mut rand = thread_rng();
+    let stars_pack = stars_pack_query.single();
+    let director = &space.director;
+
+    let mut positions: Vec<_> = fish_query
+        .iter()
+        .map(|transform| transform.translation().truncate())
+        .collect();
+    let ships = space.ships();
+
+    for _ in 0..director.timer.times_finished_this_tick() {
+        if positions.len() >= director.cap {
+            break;
+        }
+        let Some(position) =
+            director.pick_position(area, &positions, &ships, &mut rand)
+        else {
+            break;
+        };
+        positions.push(position);
+
+        let child = spawn_floating_fish(
+            &mut commands,
+            &asset_server,
+            random_fish_kind(),
+            *movement,
+            position.extend(1.),
+        );
+        commands.entity(stars_pack).add_child(child);
+    }
+}
+
+/// Moves schooling fish by boids rules and turns them back before they
+/// leave the arena.
+pub fn school_fish(
+    mut fish_query: Query<(&mut Transform, &mut Sprite, &mut SchoolingFish)>,
+    ships_query: ShipQuery<&GlobalTransform>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    rules: Res<SchoolingRules>,
+    time: Res<Time>,
+) {
+    let area = spawn_area(arena.rect(window_query.get_single().unwrap()));
+    let school: Vec<_> = fish_query
+        .iter()
+        .map(|(transform, _, fish)| {
+            (transform.translation.truncate(), fish.velocity)
+        })
+        .collect();
+    let ships: Vec<_> = ships_query
+        .iter()
+        .map(|transform| transform.translation().truncate())
+        .collect();
+
+    for (mut transform, mut sprite, mut fish) in fish_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let mut acceleration = rules.acceleration(
+            position,
+            fish.velocity,
+            school.iter().copied(),
+            ships.iter().copied(),
+        );
+        let back_inside = position.clamp(area.min, area.max) - position;
+        acceleration += back_inside.normalize_or_zero() * rules.flee;
+
+        fish.velocity = rules
+            .clamp_speed(fish.velocity + acceleration * time.delta_seconds());
+        transform.translation +=
+            (fish.velocity * time.delta_seconds()).extend(0.);
+
+        // Sprite looks to the left, so it's flipped to stay upright
+        let heading = -fish.velocity;
+        transform.rotation = Quat::from_rotation_z(heading.y.atan2(heading.x));
+        sprite.flip_y = fish.velocity.x > 0.;
+    }
+}
+
+/// Turns fish which Kitty lost on hit into pickups flying away from dog.
+pub fn spawn_dropped_fish(
+    mut commands: Commands,
+    mut hit_events: EventReader<PlayerHit>,
+    asset_server: Res<AssetServer>,
+) {
+    if let Some(event) = hit_events.iter().next() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..event.drop_count {
+            let direction = event
+                .hit_normal
+                .rotated(std::f32::consts::PI)
+                .rotated(rng.gen_range(-0.9..0.9));
+            let velocity = rng.gen_range(200.0..300.0);
+            let position = (event.position.truncate()
+                + Vec2::new(rng.gen(), rng.gen()).normalize() * 20.)
+                .extend(1.);
+
+            spawn_pickable_fish(
+                &mut commands,
+                &asset_server,
+                FishKind::Common,
+                position,
+                direction,
+                velocity,
+            );
+        }
+    }
+}
+
+/// Spawns a fish which flies in `direction`, slows down and can be picked
+/// up after a short delay.
+pub fn spawn_pickable_fish(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    kind: FishKind,
+    position: Vec3,
+    direction: Vec2,
+    velocity: f32,
 ) {
-    if star_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-        let mut rand = thread_rng();
-        let stars_pack = stars_pack_query.single();
-
-        let is_there_any_enemies = enemy_query.iter().next().is_some();
-        if is_there_any_enemies {
-            let rand_x = rand.gen::<f32>() * window.width();
-            let rand_y = rand.gen::<f32>() * window.height();
-
-            let child = commands
-                .spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            custom_size: Some(FISH_SIZE),
-                            ..default()
-                        },
-                        transform: Transform::from_xyz(rand_x, rand_y, 1.)
-                            .with_rotation(Quat::from_rotation_z(
-                                rand.gen_range(0.0..std::f32::consts::PI * 2.),
-                            )),
-                        texture: asset_server.load("sprites/Fish.png"),
-                        ..default()
-                    },
-                    Collider::ball(FISH_SIZE.x / 2.),
-                    Sensor::default(),
-                    Fish {},
-                    Animator::new(get_fish_tween(Vec3::new(
-                        rand_x, rand_y, 1.,
-                    ))),
-                ))
-                .id();
-            commands.entity(stars_pack).add_child(child);
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(FISH_SIZE),
+                ..default()
+            },
+            transform: Transform::from_translation(position)
+                .with_rotation(Quat::from_rotation_z(rand::thread_rng().gen())),
+            texture: asset_server.load(kind.texture_path()),
+            ..default()
+        },
+        DroppedFish {
+            kind,
+            direction,
+            velocity,
+            lifetime: Timer::from_seconds(
+                DROPPED_FISH_LIFETIME,
+                TimerMode::Once,
+            ),
+            pickup_delay: Timer::from_seconds(
+                DROPPED_FISH_PICKUP_DELAY,
+                TimerMode::Once,
+            ),
+        },
+    ));
+}
+
+pub fn move_dropped_fish(
+    mut commands: Commands,
+    mut dropped_fish_query: Query<(
+        Entity,
+        &mut Sprite,
+        &mut Transform,
+        &mut DroppedFish,
+    )>,
+    time: Res<Time>,
+) {
+    for (entity, mut sprite, mut transform, mut fish) in
+        dropped_fish_query.iter_mut()
+    {
+        if fish.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
         }
+
+        if fish.pickup_delay.tick(time.delta()).just_finished() {
+            commands.entity(entity).insert((
+                Collider::ball(FISH_SIZE.x / 2.),
+                Sensor,
+                Fish { kind: fish.kind },
+            ));
+        }
+
+        transform.translation +=
+            (fish.direction * fish.velocity * time.delta_seconds()).extend(0.);
+        fish.velocity = (fish.velocity - time.delta_seconds() * 87.).max(0.);
+
+        // Fade out before disappearing
+        let time_left = fish.lifetime.remaining_secs();
+        sprite
+            .color
+            .set_a((time_left / DROPPED_FISH_FADE_TIME).min(1.));
+    }
+}
+
+pub fn despawn_dropped_fish(
+    mut commands: Commands,
+    dropped_fish_query: Query<Entity, With<DroppedFish>>,
+) {
+    for entity in dropped_fish_query.iter() {
+        commands.entity(entity).despawn();
     }
 }
 
-fn get_fish_tween(start: Vec3) -> Tween<Transform> {
+/// Kitty picks fish up with its `PickupArea`, dogs with their own bodies.
+fn picker(
+    pickup_area_query: &Query<&Parent, With<PickupArea>>,
+    entity: Entity,
+) -> Entity {
+    pickup_area_query
+        .get(entity)
+        .map_or(entity, |parent| parent.get())
+}
+
+pub fn get_fish_tween(start: Vec3) -> Tween<Transform> {
     let mut rand = rand::thread_rng();
     let rand_x = rand.gen_range(3.0..15.0);
     let rand_y = rand.gen_range(3.0..15.0);
@@ -223,9 +486,9 @@ fn get_fish_tween(start: Vec3) -> Tween<Transform> {
     tween
 }
 
-fn get_random_pick_fish_sample<'a>(
-    sample_pack: &'a Res<SamplePack>,
-) -> &'a Handle<AudioSource> {
+fn get_random_pick_fish_sample(
+    sample_pack: &SamplePack,
+) -> &Handle<AudioSource> {
     match rand::thread_rng().gen_range(0..21) {
         0 => &sample_pack.pick_fish1,
         1 => &sample_pack.pick_fish2,
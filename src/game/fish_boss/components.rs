@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::{BOSS_HEALTH, RAM_COOLDOWN};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+#[derive(Component)]
+pub struct FishBoss {
+    pub health: u32,
+    pub max_health: u32,
+    /// Position on the figure-eight path, in radians.
+    pub path_angle: f32,
+    /// Boss can't be hurt until it is finished.
+    pub ram_cooldown: Timer,
+}
+
+impl Default for FishBoss {
+    fn default() -> Self {
+        let mut ram_cooldown =
+            Timer::from_seconds(RAM_COOLDOWN, TimerMode::Once);
+        ram_cooldown.tick(ram_cooldown.duration());
+        FishBoss {
+            health: BOSS_HEALTH,
+            max_health: BOSS_HEALTH,
+            path_angle: 0.,
+            ram_cooldown,
+        }
+    }
+}
+
+impl FishBoss {
+    /// Takes one health unit if cooldown allows, returns whether the boss
+    /// was hurt.
+    pub fn try_hurt(&mut self) -> bool {
+        if !self.ram_cooldown.finished() || self.health == 0 {
+            return false;
+        }
+        self.health -= 1;
+        self.ram_cooldown.reset();
+        true
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.health == 0
+    }
+
+    pub fn health_fraction(&self) -> f32 {
+        self.health as f32 / self.max_health as f32
+    }
+}
+
+#[derive(Component)]
+pub struct BossAnimation(pub Timer);
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn boss_is_hurt_once_per_cooldown() {
+        let mut boss = FishBoss::default();
+        assert!(boss.try_hurt());
+        assert!(!boss.try_hurt());
+        assert_eq!(boss.health, BOSS_HEALTH - 1);
+
+        for _ in 1..BOSS_HEALTH {
+            boss.ram_cooldown
+                .tick(Duration::from_secs_f32(RAM_COOLDOWN));
+            assert!(boss.try_hurt());
+        }
+        assert!(boss.is_defeated());
+        assert_eq!(boss.health_fraction(), 0.);
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::{resources::BossMilestones, systems::*};
+use super::SimulationState;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+pub mod components;
+pub mod resources;
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+const BOSS_HEALTH: u32 = 8;
+const BOSS_SIZE: Vec2 = Vec2::new(480. / 2.2, 363. / 2.2);
+const BOSS_FRAME_SIZE: Vec2 = Vec2::new(480., 363.);
+const BOSS_FRAMES: usize = 8;
+const BOSS_FRAME_TIME: f32 = 0.1;
+const BOSS_SPEED: f32 = 260.;
+/// Angular speed of the boss along its figure-eight path.
+const BOSS_PATH_SPEED: f32 = 0.45;
+/// Kitty has to fly at least that fast to hurt the boss.
+const RAM_SPEED: f32 = 180.;
+/// Time after a ram when the boss can't be hurt again.
+const RAM_COOLDOWN: f32 = 1.;
+const CRACKERS_PER_RAM: u32 = 3;
+const CRACKERS_ON_DEFEAT: u32 = 12;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Fish boss phase, which starts on score milestones from the progression
+/// table.
+#[derive(States, Clone, Copy, PartialEq, Eq, Default, Debug, Hash)]
+pub enum BossState {
+    #[default]
+    Inactive,
+    Fight,
+}
+
+pub struct FishBossPlugin;
+
+impl Plugin for FishBossPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // States
+            .add_state::<BossState>()
+            // Events
+            .add_event::<BossRammedEvent>()
+            .add_event::<BossSpawnRequest>()
+            // Resources
+            .init_resource::<BossMilestones>()
+            // Enter State Systems
+            .add_system(
+                reset_boss_milestones.in_schedule(OnEnter(AppState::Game)),
+            )
+            .add_systems(
+                (spawn_fish_boss, play_boss_theme)
+                    .in_schedule(OnEnter(BossState::Fight)),
+            )
+            // Systems
+            .add_system(
+                start_boss_fight_on_milestone
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(BossState::Inactive)),
+            )
+            .add_systems(
+                (
+                    fish_boss_movement,
+                    animate_fish_boss,
+                    ram_fish_boss,
+                    release_crackers.after(ram_fish_boss),
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .in_set(OnUpdate(BossState::Fight)),
+            )
+            // Exit State Systems
+            .add_systems(
+                (despawn_fish_boss, stop_boss_theme)
+                    .in_schedule(OnExit(BossState::Fight)),
+            )
+            .add_system(
+                end_boss_fight_on_exit_state
+                    .in_schedule(OnExit(AppState::Game)),
+            );
+    }
+}
+
+// Events
+
+/// Kitty's score has reached a boss milestone.
+pub struct BossSpawnRequest;
+
+/// Kitty rammed the boss hard enough to hurt it.
+pub struct BossRammedEvent {
+    pub position: Vec3,
+    pub defeated: bool,
+}
@@ -0,0 +1,48 @@
+use bevy::{prelude::*, utils::HashSet};
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::score::assets::ProgressionData;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Boss milestones reached in the current run. Score may cross a milestone
+/// again after a hit or a rotten fish, the boss comes only once.
+#[derive(Resource, Default)]
+pub struct BossMilestones(pub HashSet<u32>);
+
+impl BossMilestones {
+    /// Returns true if `score` is a boss milestone reached for the first
+    /// time in this run.
+    pub fn reach(&mut self, score: u32, progression: &ProgressionData) -> bool {
+        progression.spawns_boss(score) && self.0.insert(score)
+    }
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recrossed_milestone_brings_no_boss() {
+        let progression: ProgressionData = serde_json::from_str(
+            r#"{
+                "stages": [
+                    { "from_score": 0, "messages": {}, "dogs": {},
+                      "boss": { "at": [50] } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut milestones = BossMilestones::default();
+
+        // Kitty reaches the milestone, is hit back to 45 and climbs again
+        let fights = (48..=52)
+            .chain(46..=55)
+            .filter(|score| milestones.reach(*score, &progression))
+            .count();
+        assert_eq!(fights, 1);
+    }
+}
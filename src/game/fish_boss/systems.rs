@@ -0,0 +1,292 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_rapier2d::prelude::*;
+use kira::sound::static_sound::StaticSoundSettings;
+use rand::Rng;
+use std::time::Duration;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::{BossAnimation, FishBoss};
+use super::resources::BossMilestones;
+use super::{
+    BossRammedEvent, BossSpawnRequest, BossState, BOSS_FRAMES, BOSS_FRAME_SIZE,
+    BOSS_FRAME_TIME, BOSS_PATH_SPEED, BOSS_SIZE, BOSS_SPEED,
+    CRACKERS_ON_DEFEAT, CRACKERS_PER_RAM, RAM_SPEED,
+};
+use crate::audio::assets::AudioSource;
+use crate::audio::resources::{KiraManager, SamplePack, SoundHandleResource};
+use crate::game::fish::components::FishKind;
+use crate::game::fish::systems::spawn_pickable_fish;
+use crate::game::player::components::Player;
+use crate::game::resources::Arena;
+use crate::AppState;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn reset_boss_milestones(mut commands: Commands) {
+    commands.insert_resource(BossMilestones::default());
+}
+
+/// Starts the boss fight when Kitty's score passes a boss milestone.
+pub fn start_boss_fight_on_milestone(
+    mut spawn_requests: EventReader<BossSpawnRequest>,
+    mut next_boss_state: ResMut<NextState<BossState>>,
+) {
+    if spawn_requests.iter().next().is_some() {
+        next_boss_state.set(BossState::Fight);
+    }
+}
+
+pub fn spawn_fish_boss(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+) {
+    let arena = arena.rect(window_query.get_single().unwrap());
+    let texture_atlas = texture_atlases.add(TextureAtlas::from_grid(
+        asset_server.load("sprites/Fish boss Spritesheet.png"),
+        BOSS_FRAME_SIZE,
+        3,
+        3,
+        None,
+        None,
+    ));
+
+    // Boss swims in from the right side of the arena
+    commands.spawn((
+        SpriteSheetBundle {
+            sprite: TextureAtlasSprite {
+                custom_size: Some(BOSS_SIZE),
+                index: 0,
+                ..default()
+            },
+            texture_atlas,
+            transform: Transform::from_xyz(
+                arena.max.x + BOSS_SIZE.x,
+                arena.center().y,
+                9.,
+            ),
+            ..default()
+        },
+        RigidBody::KinematicVelocityBased,
+        Velocity::zero(),
+        Collider::ball(BOSS_SIZE.y * 0.45),
+        FishBoss::default(),
+        BossAnimation(Timer::from_seconds(
+            BOSS_FRAME_TIME,
+            TimerMode::Repeating,
+        )),
+        Name::new("Fish boss"),
+    ));
+}
+
+/// Moves the boss along a figure-eight around the arena center.
+pub fn fish_boss_movement(
+    mut boss_query: Query<(
+        &Transform,
+        &mut Velocity,
+        &mut TextureAtlasSprite,
+        &mut FishBoss,
+    )>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+) {
+    let arena = arena.rect(window_query.get_single().unwrap());
+    let center = arena.center();
+
+    for (transform, mut velocity, mut sprite, mut boss) in boss_query.iter_mut()
+    {
+        boss.ram_cooldown.tick(time.delta());
+        boss.path_angle += BOSS_PATH_SPEED * time.delta_seconds();
+
+        let (sin, cos) = boss.path_angle.sin_cos();
+        let target = center
+            + Vec2::new(
+                sin * arena.width() * 0.3,
+                sin * cos * arena.height() * 0.5,
+            );
+        velocity.linvel = ((target - transform.translation.truncate()) * 2.)
+            .clamp_length_max(BOSS_SPEED);
+
+        // Boss is drawn facing left
+        sprite.flip_x = velocity.linvel.x > 0.;
+    }
+}
+
+pub fn animate_fish_boss(
+    mut boss_query: Query<(&mut TextureAtlasSprite, &mut BossAnimation)>,
+    time: Res<Time>,
+) {
+    for (mut sprite, mut animation) in boss_query.iter_mut() {
+        if animation.0.tick(time.delta()).just_finished() {
+            sprite.index = (sprite.index + 1) % BOSS_FRAMES;
+        }
+    }
+}
+
+pub fn ram_fish_boss(
+    mut collision_events: EventReader<CollisionEvent>,
+    player_query: Query<(Entity, &Velocity), With<Player>>,
+    mut boss_query: Query<(&Transform, &mut FishBoss)>,
+    mut rammed_events: EventWriter<BossRammedEvent>,
+    mut next_boss_state: ResMut<NextState<BossState>>,
+) {
+    for event in collision_events.iter() {
+        let CollisionEvent::Started(entity1, entity2, _) = event else {
+            continue;
+        };
+        for (player, velocity) in player_query.iter() {
+            let other = if player == *entity1 {
+                *entity2
+            } else if player == *entity2 {
+                *entity1
+            } else {
+                continue;
+            };
+            let Ok((transform, mut boss)) = boss_query.get_mut(other) else {
+                continue;
+            };
+
+            if velocity.linvel.length() < RAM_SPEED || !boss.try_hurt() {
+                continue;
+            }
+            rammed_events.send(BossRammedEvent {
+                position: transform.translation,
+                defeated: boss.is_defeated(),
+            });
+            if boss.is_defeated() {
+                next_boss_state.set(BossState::Inactive);
+            }
+        }
+    }
+}
+
+/// Scatters crackers out of the rammed boss, all of them on defeat.
+pub fn release_crackers(
+    mut commands: Commands,
+    mut rammed_events: EventReader<BossRammedEvent>,
+    asset_server: Res<AssetServer>,
+    mut kira_manager: NonSendMut<KiraManager>,
+    audio_assets: Res<Assets<AudioSource>>,
+    sample_pack: Res<SamplePack>,
+) {
+    let mut rng = rand::thread_rng();
+    for event in rammed_events.iter() {
+        let count = if event.defeated {
+            CRACKERS_ON_DEFEAT
+        } else {
+            CRACKERS_PER_RAM
+        };
+        for _ in 0..count {
+            let direction =
+                Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::PI * 2.));
+            let position = (event.position.truncate()
+                + direction * BOSS_SIZE.y / 2.)
+                .extend(1.);
+            spawn_pickable_fish(
+                &mut commands,
+                &asset_server,
+                FishKind::Common,
+                position,
+                direction,
+                rng.gen_range(250.0..350.0),
+            );
+        }
+
+        // Play ram sound
+        let sound_data = audio_assets
+            .get(&sample_pack.wall_collision)
+            .unwrap()
+            .get()
+            .with_settings(
+                StaticSoundSettings::new()
+                    .volume(0.8)
+                    .output_destination(kira_manager.get_master()),
+            );
+        kira_manager.play(sound_data).unwrap();
+    }
+}
+
+pub fn despawn_fish_boss(
+    mut commands: Commands,
+    boss_query: Query<Entity, With<FishBoss>>,
+) {
+    for entity in boss_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn end_boss_fight_on_exit_state(
+    mut next_boss_state: ResMut<NextState<BossState>>,
+) {
+    next_boss_state.set(BossState::Inactive);
+}
+
+/// Pauses the main theme and plays a faster title theme during the fight.
+pub fn play_boss_theme(
+    mut kira_manager: NonSendMut<KiraManager>,
+    audio_assets: Res<Assets<AudioSource>>,
+    sample_pack: Res<SamplePack>,
+    mut sound_handle: ResMut<SoundHandleResource>,
+) {
+    let tween = kira::tween::Tween {
+        duration: Duration::from_millis(500),
+        ..default()
+    };
+    if let Some(ref mut handle) = sound_handle.main_theme {
+        if let Err(e) = handle.pause(tween) {
+            println!("Error main theme pausing: {}", e);
+        }
+    }
+    if let Some(ref clock) = sound_handle.main_theme_clock {
+        if let Err(e) = clock.pause() {
+            println!("Error main theme clock pausing: {}", e);
+        }
+    }
+
+    let sound_data = audio_assets
+        .get(&sample_pack.title_theme)
+        .unwrap()
+        .get()
+        .with_settings(
+            StaticSoundSettings::new()
+                .volume(0.7)
+                .playback_rate(1.2)
+                .loop_region(..)
+                .output_destination(kira_manager.get_master()),
+        );
+    sound_handle.boss_theme = Some(kira_manager.play(sound_data).unwrap());
+}
+
+/// Brings the main theme back if the game goes on after the fight.
+pub fn stop_boss_theme(
+    mut sound_handle: ResMut<SoundHandleResource>,
+    app_state: Res<State<AppState>>,
+) {
+    let tween = kira::tween::Tween {
+        duration: Duration::from_millis(500),
+        ..default()
+    };
+    if let Some(mut handle) = sound_handle.boss_theme.take() {
+        if let Err(e) = handle.stop(tween) {
+            println!("Error boss theme stopping: {}", e);
+        }
+    }
+
+    if app_state.0 != AppState::Game {
+        return;
+    }
+    if let Some(ref mut handle) = sound_handle.main_theme {
+        if let Err(e) = handle.resume(tween) {
+            println!("Error main theme resuming: {}", e);
+        }
+    }
+    if let Some(ref clock) = sound_handle.main_theme_clock {
+        if let Err(e) = clock.start() {
+            println!("Error main theme clock starting: {}", e);
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::{prelude::*, utils::HashMap};
 
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::player::components::PlayerId;
+
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 #[derive(Component)]
@@ -11,8 +15,33 @@ pub struct MessagesList;
 #[derive(Component)]
 pub struct Message(pub Timer);
 
+/// Row with Kitty's hearts, one per point of `Player::max_health`.
+#[derive(Component)]
+pub struct HeartsRow(pub PlayerId);
+
+/// Heart of the HUD, `index` counts from the left one.
 #[derive(Component, Debug)]
-pub struct HeartImage(pub u64, pub Handle<Image>, pub Handle<Image>);
+pub struct HeartImage {
+    pub player: PlayerId,
+    pub index: u8,
+    pub full: Handle<Image>,
+    pub empty: Handle<Image>,
+}
+
+/// Shown in the HUD while Kitty carries a shield.
+#[derive(Component)]
+pub struct ShieldIndicator(pub PlayerId);
+
+/// Part of the water tank bar which shows water gun ammo.
+#[derive(Component)]
+pub struct WaterTankFill(pub PlayerId);
+
+/// Health bar shown on top of the screen during the fish boss fight.
+#[derive(Component)]
+pub struct BossHealthBar;
+
+#[derive(Component)]
+pub struct BossHealthFill;
 
 #[derive(Component)]
 pub struct ChartBlock {
@@ -3,6 +3,8 @@ use bevy::prelude::*;
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use self::systems::*;
+use super::fish_boss::BossState;
+use super::player::components::PlayerId;
 use crate::AppState;
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
@@ -16,8 +18,12 @@ mod systems;
 // ───── Constants ────────────────────────────────────────────────────────── //
 
 pub const CHART_SIZE: usize = 3;
+/// Ids of heart animations are an offset plus heart index of every Kitty,
+/// so each offset leaves room for a heart per possible `Player::max_health`.
+const HEARTS_PER_PLAYER: u64 = u8::MAX as u64 + 1;
+const HEART_IDS: u64 = HEARTS_PER_PLAYER * PlayerId::ALL.len() as u64;
 const HIT_EVENTS_OFFSET: u64 = 400;
-const REGEN_EVENTS_OFFSET: u64 = 450;
+const REGEN_EVENTS_OFFSET: u64 = HIT_EVENTS_OFFSET + HEART_IDS;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -32,16 +38,31 @@ impl Plugin for GameUiPlugin {
             .add_systems(
                 (
                     update_messages,
+                    update_pickup_messages,
+                    update_hearts_count,
                     listen_hit_events,
                     listen_regeneration_events,
+                    update_shield_indicator,
+                    update_water_tank,
+                    update_boss_health_bar,
                     spawn_rows_from_backend,
+                    scale_hud_with_view,
                 )
                     .in_set(OnUpdate(AppState::Game)),
             )
             .add_system(
                 remove_message_on_timeout.in_set(OnUpdate(AppState::Game)),
             )
+            .add_system(
+                spawn_boss_health_bar.in_schedule(OnEnter(BossState::Fight)),
+            )
             // Exit State Systems
-            .add_system(despawn_hud.in_schedule(OnExit(AppState::Game)));
+            .add_systems(
+                (despawn_hud, reset_hud_scale)
+                    .in_schedule(OnExit(AppState::Game)),
+            )
+            .add_system(
+                despawn_boss_health_bar.in_schedule(OnExit(BossState::Fight)),
+            );
     }
 }
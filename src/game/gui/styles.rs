@@ -63,6 +63,52 @@ pub const STARSHIP_LIFE: Style = Style {
     ..Style::DEFAULT
 };
 
+pub const SHIELD_INDICATOR: Style = Style {
+    size: Size::new(Val::Px(128. / 3.), Val::Px(148. / 3.)),
+    margin: UiRect::all(Val::Px(10.)),
+    align_self: AlignSelf::Center,
+    ..Style::DEFAULT
+};
+
+pub const WATER_TANK: Style = Style {
+    size: Size::new(Val::Px(180.), Val::Px(14.)),
+    margin: UiRect::new(Val::Px(10.), Val::Px(10.), Val::Px(0.), Val::Px(10.)),
+    padding: UiRect::all(Val::Px(2.)),
+    ..Style::DEFAULT
+};
+
+pub const WATER_TANK_FILL: Style = Style {
+    size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+    ..Style::DEFAULT
+};
+
+pub const WATER_TANK_COLOR: Color = Color::rgba(1., 1., 1., 0.25);
+pub const WATER_COLOR: Color = Color::rgb(0.24, 0.63, 1.);
+
+pub const BOSS_BAR_CONTAINER: Style = Style {
+    position_type: PositionType::Absolute,
+    size: Size::new(Val::Percent(100.), Val::Auto),
+    flex_direction: FlexDirection::Column,
+    align_items: AlignItems::Center,
+    position: UiRect::new(Val::Px(0.), Val::Auto, Val::Px(12.), Val::Auto),
+    ..Style::DEFAULT
+};
+
+pub const BOSS_BAR: Style = Style {
+    size: Size::new(Val::Px(420.), Val::Px(18.)),
+    margin: UiRect::new(Val::Px(0.), Val::Px(0.), Val::Px(4.), Val::Px(0.)),
+    padding: UiRect::all(Val::Px(3.)),
+    ..Style::DEFAULT
+};
+
+pub const BOSS_BAR_FILL: Style = Style {
+    size: Size::new(Val::Percent(100.), Val::Percent(100.)),
+    ..Style::DEFAULT
+};
+
+pub const BOSS_BAR_COLOR: Color = Color::rgba(0., 0., 0., 0.5);
+pub const BOSS_HEALTH_COLOR: Color = Color::rgb(0.96, 0.73, 0.2);
+
 pub const MESSAGES_BAR: Style = Style {
     size: Size::new(Val::Percent(100.), Val::Px(300.)),
     flex_direction: FlexDirection::Column,
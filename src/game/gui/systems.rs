@@ -2,26 +2,37 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
 use bevy_tweening::lens::UiPositionLens;
 use bevy_tweening::{Animator, EaseFunction, Tween, TweenCompleted};
 
 // ───── Current Crate Import ─────────────────────────────────────────────── //
 
 use super::animation::animate_heart_out;
-use super::{components::*, styles::*, HIT_EVENTS_OFFSET, REGEN_EVENTS_OFFSET};
+use super::{
+    components::*, styles::*, HEARTS_PER_PLAYER, HEART_IDS, HIT_EVENTS_OFFSET,
+    REGEN_EVENTS_OFFSET,
+};
+use crate::events::PlayerHit;
 use crate::game::enemy::EnemyIsArrivingEvent;
-use crate::game::player::LIVES_COUNT;
-use crate::game::regeneration::{MilkEscapedEvent, RegeneratePlayerEvent};
+use crate::game::fish_boss::components::FishBoss;
+use crate::game::pickups::resources::PickupRegistry;
+use crate::game::pickups::{PickupCollectedEvent, PickupEscapedEvent};
+use crate::game::player::components::{Player, PlayerId};
+use crate::game::player::resources::PlayMode;
+use crate::game::regeneration::RegeneratePlayerEvent;
 use crate::game::score::resources::Chart;
 use crate::game::score::{ScoreEventType, ScoreUpdateEvent};
-use crate::{events::PlayerHit, game::player::components::Player};
+use crate::game::shield::components::Shield;
+use crate::game::water_gun::components::WaterGun;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let img1 = asset_server.load("sprites/Starship - life.png");
-    let img2 = asset_server.load("sprites/Starship - no life.png");
-
+pub fn spawn_hud(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    play_mode: Res<PlayMode>,
+) {
     commands
         .spawn((
             NodeBundle {
@@ -55,24 +66,9 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
                     ..default()
                 })
                 .with_children(|parent| {
-                    parent
-                        .spawn(NodeBundle {
-                            style: HEARTS_ROW,
-                            ..default()
-                        })
-                        .with_children(|parent| {
-                            for mut id in 1..=LIVES_COUNT {
-                                id += HIT_EVENTS_OFFSET;
-                                parent.spawn((
-                                    ImageBundle {
-                                        style: STARSHIP_LIFE,
-                                        image: img1.clone().into(),
-                                        ..default()
-                                    },
-                                    HeartImage(id, img1.clone(), img2.clone()),
-                                ));
-                            }
-                        });
+                    for id in play_mode.players() {
+                        spawn_player_panel(parent, *id, &asset_server);
+                    }
                     parent.spawn((
                         NodeBundle {
                             style: MESSAGES_BAR,
@@ -84,6 +80,50 @@ pub fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
         });
 }
 
+/// Hearts, shield indicator and water tank of one Kitty.
+fn spawn_player_panel(
+    parent: &mut ChildBuilder,
+    id: PlayerId,
+    asset_server: &Res<AssetServer>,
+) {
+    // Hearts are added by `update_hearts_count`
+    parent
+        .spawn((
+            NodeBundle {
+                style: HEARTS_ROW,
+                ..default()
+            },
+            HeartsRow(id),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ImageBundle {
+                    style: SHIELD_INDICATOR,
+                    image: asset_server.load("sprites/Shield.png").into(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                ShieldIndicator(id),
+            ));
+        });
+    parent
+        .spawn(NodeBundle {
+            style: WATER_TANK,
+            background_color: WATER_TANK_COLOR.into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                NodeBundle {
+                    style: WATER_TANK_FILL,
+                    background_color: WATER_COLOR.into(),
+                    ..default()
+                },
+                WaterTankFill(id),
+            ));
+        });
+}
+
 fn spawn_row(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
@@ -238,8 +278,6 @@ pub fn update_messages(
     asset_server: Res<AssetServer>,
     mut score_update_event: EventReader<ScoreUpdateEvent>,
     mut arriving_events: EventReader<EnemyIsArrivingEvent>,
-    mut escaped_milk_events: EventReader<MilkEscapedEvent>,
-    mut regen_events: EventReader<RegeneratePlayerEvent>,
 ) {
     for event in score_update_event.iter() {
         let suffix = match event.event_type.get_score() % 10 {
@@ -325,14 +363,33 @@ pub fn update_messages(
         let id = commands.spawn(label).id();
         commands.entity(list.single()).push_children(&[id]);
     }
-    for _ in escaped_milk_events.iter() {
+}
+
+/// Pickups tell about themselves with messages from the registry.
+pub fn update_pickup_messages(
+    mut commands: Commands,
+    list: Query<Entity, With<MessagesList>>,
+    asset_server: Res<AssetServer>,
+    mut collected_events: EventReader<PickupCollectedEvent>,
+    mut escaped_events: EventReader<PickupEscapedEvent>,
+    registry: Res<PickupRegistry>,
+) {
+    let picked_messages = collected_events.iter().filter_map(|event| {
+        let message = registry.0[&event.kind].picked_message?;
+        Some((message, Color::GREEN))
+    });
+    let escaped_messages = escaped_events.iter().filter_map(|event| {
+        let message = registry.0[&event.kind].escaped_message?;
+        Some((message, Color::RED))
+    });
+    for (message, color) in picked_messages.chain(escaped_messages) {
         let label = (
             TextBundle::from_sections([TextSection::new(
-                "The milk escaped!",
+                message,
                 TextStyle {
                     font: asset_server.load("fonts/Abaddon Bold.ttf"),
                     font_size: 25.,
-                    color: Color::RED,
+                    color,
                 },
             )]),
             Message(Timer::new(
@@ -343,26 +400,33 @@ pub fn update_messages(
         let id = commands.spawn(label).id();
         commands.entity(list.single()).push_children(&[id]);
     }
-    for _ in regen_events.iter() {
-        let label = (
-            TextBundle::from_sections([TextSection::new(
-                "The milk'd been drinked!",
-                TextStyle {
-                    font: asset_server.load("fonts/Abaddon Bold.ttf"),
-                    font_size: 25.,
-                    color: Color::GREEN,
-                },
-            )]),
-            Message(Timer::new(
-                std::time::Duration::from_secs(3),
-                TimerMode::Once,
-            )),
-        );
-        let id = commands.spawn(label).id();
-        commands.entity(list.single()).push_children(&[id]);
+}
+
+/// Camera stretches the world to the window, so HUD is scaled the same way
+/// to keep its size relative to the arena.
+pub fn scale_hud_with_view(
+    camera_query: Query<&OrthographicProjection, With<Camera2d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    // Co-op zoom shouldn't shrink the HUD, so it's left out
+    let projection = camera_query.single();
+    let view_width = projection.area.width() / projection.scale;
+    if view_width <= 0. {
+        return;
+    }
+    let scale = f64::from(window_query.single().width() / view_width);
+    // Every change of the scale makes the whole UI to lay out again
+    if (ui_scale.scale - scale).abs() > 0.001 {
+        ui_scale.scale = scale;
     }
 }
 
+/// Menus are laid out without scaling.
+pub fn reset_hud_scale(mut ui_scale: ResMut<UiScale>) {
+    ui_scale.scale = 1.;
+}
+
 pub fn remove_message_on_timeout(
     mut commands: Commands,
     mut labels_query: Query<(Entity, &mut Message, &Parent)>,
@@ -385,85 +449,239 @@ pub fn despawn_hud(
         .despawn_recursive();
 }
 
+pub fn update_shield_indicator(
+    shielded_query: Query<&PlayerId, (With<Player>, With<Shield>)>,
+    mut indicator_query: Query<(&ShieldIndicator, &mut Visibility)>,
+) {
+    for (indicator, mut visibility) in indicator_query.iter_mut() {
+        let target = if shielded_query.iter().any(|id| *id == indicator.0) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+}
+
+pub fn update_water_tank(
+    water_gun_query: Query<(&PlayerId, &WaterGun), With<Player>>,
+    mut fill_query: Query<(&WaterTankFill, &mut Style)>,
+) {
+    for (id, water_gun) in water_gun_query.iter() {
+        for (fill, mut style) in fill_query.iter_mut() {
+            let width = Val::Percent(water_gun.fill() * 100.);
+            if fill.0 == *id && style.size.width != width {
+                style.size.width = width;
+            }
+        }
+    }
+}
+
+pub fn spawn_boss_health_bar(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: BOSS_BAR_CONTAINER,
+                ..default()
+            },
+            BossHealthBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Fish boss",
+                TextStyle {
+                    font: asset_server.load("fonts/Abaddon Bold.ttf"),
+                    font_size: 25.,
+                    color: BOSS_HEALTH_COLOR,
+                },
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: BOSS_BAR,
+                    background_color: BOSS_BAR_COLOR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: BOSS_BAR_FILL,
+                            background_color: BOSS_HEALTH_COLOR.into(),
+                            ..default()
+                        },
+                        BossHealthFill,
+                    ));
+                });
+        });
+}
+
+pub fn update_boss_health_bar(
+    boss_query: Query<&FishBoss, Changed<FishBoss>>,
+    mut fill_query: Query<&mut Style, With<BossHealthFill>>,
+) {
+    if let (Ok(boss), Ok(mut style)) =
+        (boss_query.get_single(), fill_query.get_single_mut())
+    {
+        let width = Val::Percent(boss.health_fraction() * 100.);
+        if style.size.width != width {
+            style.size.width = width;
+        }
+    }
+}
+
+pub fn despawn_boss_health_bar(
+    mut commands: Commands,
+    bar_query: Query<Entity, With<BossHealthBar>>,
+) {
+    for entity in bar_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Respawns HUD hearts of every Kitty whose max health differs from their
+/// count.
+pub fn update_hearts_count(
+    mut commands: Commands,
+    player_query: Query<(&PlayerId, &Player), Changed<Player>>,
+    row_query: Query<(Entity, &HeartsRow)>,
+    heart_query: Query<(Entity, &HeartImage)>,
+    asset_server: Res<AssetServer>,
+) {
+    for (id, player) in player_query.iter() {
+        let Some((row, _)) = row_query.iter().find(|(_, row)| row.0 == *id)
+        else {
+            continue;
+        };
+        let hearts: Vec<Entity> = heart_query
+            .iter()
+            .filter(|(_, heart)| heart.player == *id)
+            .map(|(entity, _)| entity)
+            .collect();
+        if hearts.len() == player.max_health as usize {
+            continue;
+        }
+
+        for heart in hearts {
+            commands.entity(heart).despawn_recursive();
+        }
+
+        let full = asset_server.load("sprites/Starship - life.png");
+        let empty = asset_server.load("sprites/Starship - no life.png");
+        commands.entity(row).with_children(|parent| {
+            for index in 0..player.max_health {
+                let image = if index < player.health { &full } else { &empty };
+                parent.spawn((
+                    ImageBundle {
+                        style: STARSHIP_LIFE,
+                        image: image.clone().into(),
+                        ..default()
+                    },
+                    HeartImage {
+                        player: *id,
+                        index,
+                        full: full.clone(),
+                        empty: empty.clone(),
+                    },
+                ));
+            }
+        });
+    }
+}
+
+fn heart_animation_id(offset: u64, player: PlayerId, index: u8) -> u64 {
+    offset + player.index() as u64 * HEARTS_PER_PLAYER + index as u64
+}
+
+/// Player index and heart index of the heart animation with `id`, if the
+/// animation belongs to the `offset` range.
+fn animated_heart(offset: u64, id: u64) -> Option<(usize, u8)> {
+    (offset..offset + HEART_IDS).contains(&id).then(|| {
+        let id = id - offset;
+        (
+            (id / HEARTS_PER_PLAYER) as usize,
+            (id % HEARTS_PER_PLAYER) as u8,
+        )
+    })
+}
+
 pub fn listen_hit_events(
     mut commands: Commands,
     mut player_hit_events: EventReader<PlayerHit>,
     mut animation_events: EventReader<TweenCompleted>,
-    player_query: Query<&Player>,
     mut heart_images: Query<(Entity, &mut UiImage, &HeartImage)>,
 ) {
-    if let Some(_) = player_hit_events.iter().next() {
-        if let Ok(player) = player_query.get_single() {
-            let id = player.health as u64 + HIT_EVENTS_OFFSET + 1;
-            let entity = heart_images
-                .iter_mut()
-                .filter(|(_, _, heart_image)| heart_image.0 == id)
-                .next()
-                .unwrap()
-                .0;
-            animate_heart_out(&mut commands, entity, id);
-        } else {
-            let id = HIT_EVENTS_OFFSET + 1;
-            let entity = heart_images
-                .iter_mut()
-                .filter(|(_, _, heart_image)| heart_image.0 == id)
-                .next()
-                .unwrap()
-                .0;
-            animate_heart_out(&mut commands, entity, id);
+    for event in player_hit_events.iter() {
+        // Kitty lost the heart right after remaining ones
+        let index = event.remaining_health;
+        if let Some((entity, _, _)) =
+            heart_images.iter().find(|(_, _, heart)| {
+                heart.player == event.player && heart.index == index
+            })
+        {
+            animate_heart_out(
+                &mut commands,
+                entity,
+                heart_animation_id(HIT_EVENTS_OFFSET, event.player, index),
+            );
         }
     }
 
     for event in animation_events.iter() {
-        if (HIT_EVENTS_OFFSET..REGEN_EVENTS_OFFSET).contains(&event.user_data) {
-            let mut our_image = heart_images
-                .iter_mut()
-                .filter(|(_, _, heart_image)| heart_image.0 == event.user_data)
-                .next()
-                .unwrap();
-            our_image.1.texture = (our_image.2).2.clone_weak();
-            return;
+        let Some((player, index)) =
+            animated_heart(HIT_EVENTS_OFFSET, event.user_data)
+        else {
+            continue;
+        };
+        for (_, mut image, heart) in heart_images.iter_mut() {
+            if heart.player.index() == player && heart.index == index {
+                image.texture = heart.empty.clone_weak();
+            }
         }
     }
 }
 
-// FIXME
-// Some very bad decisions here, but I can't figure out how
-// to organize work with tweening and events for now.
 pub fn listen_regeneration_events(
     mut commands: Commands,
     mut regen_events: EventReader<RegeneratePlayerEvent>,
     mut animation_events: EventReader<TweenCompleted>,
-    player_query: Query<&Player>,
+    player_query: Query<&PlayerId>,
     mut heart_images: Query<(Entity, &mut UiImage, &HeartImage)>,
 ) {
-    if let Some(event) = regen_events.iter().next() {
-        if let Ok(_) = player_query.get_single() {
-            // Some bad desicion, use `HIT_EVENTS_OFFSET` here
-            let id1 = event.new_health as u64 + HIT_EVENTS_OFFSET;
-            let entity = heart_images
-                .iter_mut()
-                .filter(|(_, _, heart_image)| heart_image.0 == id1)
-                .next()
-                .unwrap()
-                .0;
-
-            let id2 = REGEN_EVENTS_OFFSET + event.new_health as u64;
-            animate_heart_out(&mut commands, entity, id2);
+    for event in regen_events.iter() {
+        let Ok(player) = player_query.get(event.player) else {
+            continue;
+        };
+        // Kitty got back the last of `new_health` hearts
+        let Some(index) = event.new_health.checked_sub(1) else {
+            continue;
+        };
+        if let Some((entity, _, _)) =
+            heart_images.iter().find(|(_, _, heart)| {
+                heart.player == *player && heart.index == index
+            })
+        {
+            animate_heart_out(
+                &mut commands,
+                entity,
+                heart_animation_id(REGEN_EVENTS_OFFSET, *player, index),
+            );
         }
     }
 
     for event in animation_events.iter() {
-        if (REGEN_EVENTS_OFFSET..500).contains(&event.user_data) {
-            let mut our_image = heart_images
-                .iter_mut()
-                .filter(|(_, _, heart_image)| {
-                    heart_image.0 == event.user_data - 50
-                })
-                .next()
-                .unwrap();
-            our_image.1.texture = (our_image.2).1.clone_weak();
-            return;
+        let Some((player, index)) =
+            animated_heart(REGEN_EVENTS_OFFSET, event.user_data)
+        else {
+            continue;
+        };
+        for (_, mut image, heart) in heart_images.iter_mut() {
+            if heart.player.index() == player && heart.index == index {
+                image.texture = heart.full.clone_weak();
+            }
         }
     }
 }
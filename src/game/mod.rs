@@ -3,30 +3,55 @@ use bevy::prelude::*;
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use self::{
-    gui::GameUiPlugin, regeneration::RegenerationPlugin, resources::GameData,
+    antenna::AntennaPlugin, effects::EffectsPlugin, fish_boss::FishBossPlugin,
+    gui::GameUiPlugin, pickups::PickupsPlugin,
+    regeneration::RegenerationPlugin, resources::Arena, resources::GameData,
+    shield::ShieldPlugin, speed_boost::SpeedBoostPlugin,
+    water_gun::WaterGunPlugin,
 };
 use crate::{events::GameOver, AppState};
 use enemy::EnemyPlugin;
 use fish::FishPlugin;
 use player::PlayerPlugin;
 use score::ScorePlugin;
+use stats::StatsPlugin;
 use systems::*;
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
 
 // Modules in folders
+mod antenna;
 pub mod components;
+pub mod effects;
 pub mod enemy;
-mod fish;
+pub mod fish;
+pub mod fish_boss;
 mod gui;
+pub mod pickups;
 pub mod player;
 mod regeneration;
 mod resources;
 pub mod score;
+pub mod shield;
+mod speed_boost;
+pub mod stats;
+pub mod water_gun;
 
 // Top-level modules
 mod systems;
 
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+/// Default arena size, window bigger than that stretches the arena.
+const ARENA_SIZE: Vec2 = Vec2::new(2400., 1600.);
+/// How fast the camera catches up with Kitty.
+const CAMERA_STIFFNESS: f32 = 4.;
+/// Closest distance from Kitty to the view edge before the camera zooms
+/// out in co-op.
+const CAMERA_MARGIN: f32 = 120.;
+/// Distance from walls for entities brought back into the shrunk arena.
+const RESIZE_MARGIN: f32 = 60.;
+
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 pub struct GamePlugin;
@@ -40,13 +65,15 @@ impl Plugin for GamePlugin {
             .add_state::<SimulationState>()
             // Resources
             .init_resource::<GameData>()
+            .init_resource::<Arena>()
             // Enter State Systems
             .add_systems(
                 (
                     resume_simulation,
                     spawn_world_borders,
                     system_play_main_theme,
-                    spawn_controls_sheet,
+                    center_camera_on_arena,
+                    spawn_controls_sheet.after(center_camera_on_arena),
                 )
                     .in_schedule(OnEnter(AppState::Game)),
             )
@@ -54,9 +81,17 @@ impl Plugin for GamePlugin {
             .add_plugin(EnemyPlugin)
             .add_plugin(PlayerPlugin)
             .add_plugin(FishPlugin)
+            .add_plugin(FishBossPlugin)
             .add_plugin(ScorePlugin)
             .add_plugin(GameUiPlugin)
+            .add_plugin(PickupsPlugin)
             .add_plugin(RegenerationPlugin)
+            .add_plugin(AntennaPlugin)
+            .add_plugin(ShieldPlugin)
+            .add_plugin(SpeedBoostPlugin)
+            .add_plugin(EffectsPlugin)
+            .add_plugin(WaterGunPlugin)
+            .add_plugin(StatsPlugin)
             // Systems
             .add_system(despawn_controls_sheet.in_set(OnUpdate(AppState::Game)))
             .add_system(
@@ -68,6 +103,9 @@ impl Plugin for GamePlugin {
                     system_check_main_theme_clock,
                     system_restart_clock,
                     detect_input,
+                    follow_players_with_camera,
+                    rebuild_world_borders_on_resize,
+                    keep_entities_inside_arena,
                 )
                     .in_set(OnUpdate(AppState::Game)),
             )
@@ -78,7 +116,12 @@ impl Plugin for GamePlugin {
             )
             // Exit State Systems
             .add_systems(
-                (pause_simulation, despawn_borders, stop_main_theme)
+                (
+                    pause_simulation,
+                    despawn_borders,
+                    stop_main_theme,
+                    reset_camera,
+                )
                     .in_schedule(OnExit(AppState::Game)),
             );
     }
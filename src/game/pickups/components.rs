@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::effects::components::StatusEffects;
+use crate::game::player::components::Player;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Items which fly through the screen for Kitty to catch. Every kind is
+/// described by a `PickupSpec` in `PickupRegistry`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PickupKind {
+    Milk,
+    Shield,
+    SpeedBoost,
+}
+
+/// Pickup on its way through the screen.
+#[derive(Component)]
+pub struct FlyingPickup {
+    pub kind: PickupKind,
+    pub direction: Vec2,
+    pub speed: f32,
+    pub rotation: f32,
+    pub covered_distance: f32,
+}
+
+/// Kitty as pickup spawn conditions see it.
+pub struct PickupTarget<'a> {
+    pub player: &'a Player,
+    pub shielded: bool,
+    pub effects: &'a StatusEffects,
+}
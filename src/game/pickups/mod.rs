@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::{
+    components::PickupKind,
+    resources::{PickupRegistry, PickupSpec, PickupTimers},
+    systems::*,
+};
+use super::SimulationState;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Spawns, moves and collects pickups of every kind registered with
+/// `register_pickup`.
+pub struct PickupsPlugin;
+
+impl Plugin for PickupsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<PickupRegistry>()
+            .init_resource::<PickupTimers>()
+            // Events
+            .add_event::<PickupCollectedEvent>()
+            .add_event::<PickupEscapedEvent>()
+            // Systems
+            .add_systems(
+                (
+                    tick_pickup_timers,
+                    spawn_pickups.after(tick_pickup_timers),
+                    move_pickups,
+                    collect_pickups,
+                    play_pickup_sound.after(collect_pickups),
+                    despawn_escaped_pickups,
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            )
+            // Exit State Systems
+            .add_system(despawn_pickups.in_schedule(OnExit(AppState::Game)));
+    }
+}
+
+pub trait RegisterPickup {
+    /// Adds a new kind of pickup, plugin of the pickup applies its effect
+    /// on `PickupCollectedEvent`.
+    fn register_pickup(
+        &mut self,
+        kind: PickupKind,
+        spec: PickupSpec,
+    ) -> &mut Self;
+}
+
+impl RegisterPickup for App {
+    fn register_pickup(
+        &mut self,
+        kind: PickupKind,
+        spec: PickupSpec,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(PickupRegistry::default)
+            .0
+            .insert(kind, spec);
+        self
+    }
+}
+
+// Events
+
+/// Kitty caught a pickup.
+pub struct PickupCollectedEvent {
+    pub kind: PickupKind,
+    pub player: Entity,
+}
+
+/// Pickup flew away uncaught.
+pub struct PickupEscapedEvent {
+    pub kind: PickupKind,
+}
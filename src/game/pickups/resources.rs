@@ -0,0 +1,62 @@
+use bevy::{prelude::*, utils::HashMap};
+use rand::Rng;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::{PickupKind, PickupTarget};
+use crate::audio::{assets::AudioSource, resources::SamplePack};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Everything pickup systems need to know about one kind of pickup. The
+/// effect itself is applied by its own plugin on `PickupCollectedEvent`.
+pub struct PickupSpec {
+    /// Pickup starts flying only while some Kitty wants it.
+    pub wanted_by: fn(&PickupTarget) -> bool,
+    /// Seconds between the moment it's wanted and the spawn, random
+    /// between these two.
+    pub spawn_delay: (f32, f32),
+    pub trajectory: Trajectory,
+    pub texture: &'static str,
+    pub size: Vec2,
+    pub collider_radius: f32,
+    pub sound: fn(&SamplePack) -> &Handle<AudioSource>,
+    pub volume: f64,
+    /// HUD messages when the pickup is caught and when it escapes.
+    pub picked_message: Option<&'static str>,
+    pub escaped_message: Option<&'static str>,
+}
+
+/// Pickups fly straight through the screen center, spinning around.
+pub struct Trajectory {
+    pub speed: f32,
+    /// Spin speed in radians per second, its direction is random.
+    pub spin: f32,
+    /// Some pickups spin like crazy once in a while.
+    pub wild_spin: f32,
+    pub wild_spin_chance: f64,
+}
+
+impl Trajectory {
+    pub fn random_spin(&self) -> f32 {
+        let mut rng = rand::thread_rng();
+        let spin = if rng.gen_bool(self.wild_spin_chance) {
+            self.wild_spin
+        } else {
+            self.spin
+        };
+        if rng.gen::<bool>() {
+            spin
+        } else {
+            -spin
+        }
+    }
+}
+
+/// Specs of every pickup kind, filled by the plugins of those pickups.
+#[derive(Resource, Default)]
+pub struct PickupRegistry(pub HashMap<PickupKind, PickupSpec>);
+
+/// Spawn delay of every pickup kind which some Kitty currently wants.
+#[derive(Resource, Default)]
+pub struct PickupTimers(pub HashMap<PickupKind, Timer>);
@@ -0,0 +1,186 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_rapier2d::prelude::*;
+use kira::sound::static_sound::StaticSoundSettings;
+use rand::Rng;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::{FlyingPickup, PickupTarget};
+use super::resources::{PickupRegistry, PickupTimers};
+use super::{PickupCollectedEvent, PickupEscapedEvent};
+use crate::audio::resources::Sfx;
+use crate::game::effects::components::StatusEffects;
+use crate::game::player::components::{PickupArea, Player};
+use crate::game::player::systems::find_picker;
+use crate::game::resources::Arena;
+use crate::game::shield::components::Shield;
+use crate::helper_functions::VectorUtilities;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Only one pickup of a kind flies at the time, and only while some Kitty
+/// wants it.
+pub fn tick_pickup_timers(
+    player_query: Query<(&Player, Option<&Shield>, &StatusEffects)>,
+    pickup_query: Query<&FlyingPickup>,
+    registry: Res<PickupRegistry>,
+    mut timers: ResMut<PickupTimers>,
+    time: Res<Time>,
+) {
+    for (kind, spec) in registry.0.iter() {
+        let wanted = player_query.iter().any(|(player, shield, effects)| {
+            (spec.wanted_by)(&PickupTarget {
+                player,
+                shielded: shield.is_some(),
+                effects,
+            })
+        });
+        let flying = pickup_query.iter().any(|pickup| pickup.kind == *kind);
+        if !wanted || flying {
+            timers.0.remove(kind);
+            continue;
+        }
+
+        let timer = timers.0.entry(*kind).or_insert_with(|| {
+            let (min, max) = spec.spawn_delay;
+            let rand_time = rand::thread_rng().gen_range(min..max);
+            Timer::from_seconds(rand_time, TimerMode::Once)
+        });
+        timer.tick(time.delta());
+    }
+}
+
+/// Launches pickups whose spawn delay has just run out.
+pub fn spawn_pickups(
+    mut commands: Commands,
+    registry: Res<PickupRegistry>,
+    timers: Res<PickupTimers>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    asset_server: Res<AssetServer>,
+) {
+    for (kind, timer) in timers.0.iter() {
+        if !timer.just_finished() {
+            continue;
+        }
+        let spec = &registry.0[kind];
+
+        let window = window_query.get_single().unwrap();
+        let center = arena.rect(window).center();
+
+        let mut rand_point = Vec2::new_rand();
+        rand_point *= arena.outskirts_radius(window);
+        rand_point += center;
+
+        let direction = (center - rand_point).normalize();
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(spec.size),
+                    ..default()
+                },
+                texture: asset_server.load(spec.texture),
+                transform: Transform::from_xyz(rand_point.x, rand_point.y, 10.),
+                ..default()
+            },
+            Sensor,
+            Collider::ball(spec.collider_radius),
+            FlyingPickup {
+                kind: *kind,
+                direction,
+                speed: spec.trajectory.speed,
+                rotation: spec.trajectory.random_spin(),
+                covered_distance: 0.,
+            },
+        ));
+    }
+}
+
+pub fn move_pickups(
+    mut pickup_query: Query<(&mut Transform, &mut FlyingPickup)>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut pickup) in pickup_query.iter_mut() {
+        let step = pickup.direction * time.delta_seconds() * pickup.speed;
+        pickup.covered_distance += step.length();
+
+        transform.translation += step.extend(0.);
+        transform.rotate_z(pickup.rotation * time.delta_seconds());
+    }
+}
+
+pub fn collect_pickups(
+    mut commands: Commands,
+    rapier_context: Res<RapierContext>,
+    pickup_query: Query<(Entity, &FlyingPickup)>,
+    player_query: Query<(), With<Player>>,
+    pickup_area_query: Query<(Entity, &Parent), With<PickupArea>>,
+    mut collected_events: EventWriter<PickupCollectedEvent>,
+) {
+    for (entity, pickup) in pickup_query.iter() {
+        let Some(player) =
+            find_picker(&rapier_context, entity, &pickup_area_query)
+        else {
+            continue;
+        };
+        if !player_query.contains(player) {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        collected_events.send(PickupCollectedEvent {
+            kind: pickup.kind,
+            player,
+        });
+    }
+}
+
+pub fn play_pickup_sound(
+    mut collected_events: EventReader<PickupCollectedEvent>,
+    registry: Res<PickupRegistry>,
+    mut sfx: Sfx,
+) {
+    for event in collected_events.iter() {
+        let spec = &registry.0[&event.kind];
+        let sound_data = sfx
+            .audio_assets
+            .get((spec.sound)(&sfx.sample_pack))
+            .unwrap()
+            .get()
+            .with_settings(
+                StaticSoundSettings::new()
+                    .volume(spec.volume)
+                    .output_destination(sfx.kira_manager.get_master()),
+            );
+        sfx.kira_manager.play(sound_data).unwrap();
+    }
+}
+
+pub fn despawn_escaped_pickups(
+    mut commands: Commands,
+    pickup_query: Query<(Entity, &FlyingPickup)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    mut escaped_events: EventWriter<PickupEscapedEvent>,
+) {
+    // Pickup flies through the center, so it's on the outskirts again
+    let max_distance = arena.outskirts_radius(window_query.single()) * 2.;
+    for (entity, pickup) in pickup_query.iter() {
+        if pickup.covered_distance > max_distance {
+            commands.entity(entity).despawn();
+            escaped_events.send(PickupEscapedEvent { kind: pickup.kind });
+        }
+    }
+}
+
+pub fn despawn_pickups(
+    mut commands: Commands,
+    pickup_query: Query<Entity, With<FlyingPickup>>,
+    mut timers: ResMut<PickupTimers>,
+) {
+    for entity in pickup_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    timers.0.clear();
+}
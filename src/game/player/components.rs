@@ -1,29 +1,88 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct Player {
     pub health: u8,
+    /// Hearts count in the HUD, upgrades and difficulty may change it.
+    pub max_health: u8,
 }
 
-#[derive(Component)]
-pub struct PlayerInvulnerableTimer(pub Timer);
+impl Player {
+    /// Kitty with full health.
+    pub fn new(max_health: u8) -> Self {
+        Player {
+            health: max_health,
+            max_health,
+        }
+    }
+
+    pub fn is_hurt(&self) -> bool {
+        self.health < self.max_health
+    }
+}
+
+/// Tells locally controlled Kitties apart in controls, HUD and scores.
+#[derive(
+    Component,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+pub enum PlayerId {
+    One,
+    Two,
+}
+
+impl PlayerId {
+    pub const ALL: [PlayerId; 2] = [PlayerId::One, PlayerId::Two];
+
+    pub fn index(&self) -> usize {
+        match self {
+            PlayerId::One => 0,
+            PlayerId::Two => 1,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlayerId::One => "Kitty",
+            PlayerId::Two => "Kitty 2",
+        }
+    }
+
+    pub fn avatar_path(&self) -> &'static str {
+        match self {
+            PlayerId::One => "sprites/Avatars/Frame Kitty.png",
+            PlayerId::Two => "sprites/Avatars/Frame Kitty 2.png",
+        }
+    }
 
+    /// Tint of the starship and the face in high scores.
+    pub fn color(&self) -> Color {
+        match self {
+            PlayerId::One => Color::WHITE,
+            PlayerId::Two => Color::rgb(1., 0.72, 0.5),
+        }
+    }
+}
+
+/// Child sensor of the Kitty which picks fish and power-ups up.
 #[derive(Component)]
-pub struct RocketEngineParticles;
+pub struct PickupArea;
 
 #[derive(Component)]
-pub struct RocketEngineSprite;
+pub struct PlayerInvulnerableTimer(pub Timer);
 
 #[derive(Component)]
-pub struct DropFishParticle {
-    pub direction: Vec2,
-    pub velocity: f32,
-    pub timer: Timer,
-}
+pub struct RocketEngineParticles;
 
 #[derive(Component)]
-pub struct SmokeParticle {
-    pub direction: Vec2,
-    pub velocity: f32,
-    pub timer: Timer,
-}
+pub struct RocketEngineSprite;
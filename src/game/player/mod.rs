@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
-use self::systems::*;
+use self::{resources::PlayMode, systems::*};
 use super::SimulationState;
 use crate::{events::PlayerHit, AppState};
 
@@ -10,13 +10,20 @@ use crate::{events::PlayerHit, AppState};
 
 // Top-level modules
 pub mod components;
+pub mod resources;
 pub mod systems;
 
 // ───── Constants ────────────────────────────────────────────────────────── //
 
 pub const PLAYER_SPEED: f32 = 25000.;
-pub const LIVES_COUNT: u64 = 3;
+pub const PLAYER_LINEAR_DAMPING: f32 = 0.6;
+pub const ENGINE_VOLUME: f64 = 0.21;
+/// Thrust change which makes engine sound volume follow it.
+pub const ENGINE_THRUST_STEP: f32 = 0.05;
+pub const PLAYER_MAX_HEALTH: u8 = 3;
 pub const SPACESHIP_SIZE: f32 = 64.;
+/// Radius of the area collecting pickups, without status effects.
+pub const PICKUP_RADIUS: f32 = SPACESHIP_SIZE / 2.;
 pub const DOG_SIZE: Vec2 = Vec2::new(156., 154.);
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
@@ -26,70 +33,41 @@ pub enum PlayerSystemSet {
     Movement,
 }
 
-#[derive(States, Clone, PartialEq, Eq, Default, Debug, Hash)]
+/// State of every Kitty, each one gets hurt and shielded on its own.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum PlayerState {
     #[default]
     Vulnerable,
     Invulnerable,
+    /// Next dog hit breaks the shield instead of hurting Kitty.
+    Shielded,
 }
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            app
-                // Enter State Systems
-                .add_system(spawn_player.in_schedule(OnEnter(AppState::Game)))
-                // Systems
-                .add_system(
-                    player_movement
-                        .in_set(PlayerSystemSet::Movement)
-                        .in_set(OnUpdate(SimulationState::Running))
-                        .in_set(OnUpdate(AppState::Game)),
-                );
-        }
-
-        #[cfg(target_arch = "wasm32")]
-        {
-            app
-                // Enter State Systems
-                .add_system(
-                    spawn_player_without_gpu_particles
-                        .in_schedule(OnEnter(AppState::Game)),
-                )
-                // Systems
-                .add_systems(
-                    (
-                        player_movement_without_gpu_particles,
-                        poll_and_despawn_smoke_particles,
-                    )
-                        .in_set(PlayerSystemSet::Movement)
-                        .in_set(OnUpdate(SimulationState::Running))
-                        .in_set(OnUpdate(AppState::Game)),
-                )
-                // Exit State Systems
-                .add_system(
-                    despawn_smoke_particles_on_exit_state
-                        .in_schedule(OnExit(AppState::Game)),
-                );
-        }
-
         app
             // Events
             .add_event::<PlayerHit>()
+            // Resources
+            .init_resource::<PlayMode>()
             // System Sets
             .configure_set(PlayerSystemSet::Movement)
-            // States
-            .add_state::<PlayerState>()
+            // Enter State Systems
+            .add_system(spawn_player.in_schedule(OnEnter(AppState::Game)))
             // Systems
+            .add_system(
+                player_movement
+                    .in_set(PlayerSystemSet::Movement)
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .in_set(OnUpdate(AppState::Game)),
+            )
             .add_systems(
                 (
                     handle_player_collision,
+                    meow_on_wall_collision,
                     regenerate_player,
-                    spawn_particles_on_collision_with_enemy,
-                    poll_and_despawn_collision_particles,
                 )
                     .in_set(OnUpdate(SimulationState::Running))
                     .in_set(OnUpdate(AppState::Game)),
@@ -97,15 +75,11 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 (count_player_invulnerability_timer, blink_player)
                     .in_set(OnUpdate(SimulationState::Running))
-                    .in_set(OnUpdate(AppState::Game))
-                    .in_set(OnUpdate(PlayerState::Invulnerable)),
+                    .in_set(OnUpdate(AppState::Game)),
             )
             // Exit State Systems
-            .add_systems(
-                (
-                    despawn_player_on_exit_game_state,
-                    despawn_collision_particles,
-                )
+            .add_system(
+                despawn_player_on_exit_game_state
                     .in_schedule(OnExit(AppState::Game)),
             );
     }
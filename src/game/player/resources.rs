@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::PlayerId;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// How many Kitties fly in the next run, chosen in the main menu.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayMode {
+    #[default]
+    Solo,
+    /// Second Kitty is controlled with its own bindings or gamepad.
+    Coop,
+}
+
+impl PlayMode {
+    pub fn players(&self) -> &'static [PlayerId] {
+        match self {
+            PlayMode::Solo => &[PlayerId::One],
+            PlayMode::Coop => &PlayerId::ALL,
+        }
+    }
+
+    pub fn toggled(&self) -> PlayMode {
+        match self {
+            PlayMode::Solo => PlayMode::Coop,
+            PlayMode::Coop => PlayMode::Solo,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlayMode::Solo => "1 player",
+            PlayMode::Coop => "2 players",
+        }
+    }
+}
@@ -1,285 +1,199 @@
+use bevy::ecs::system::SystemParam;
 use bevy::sprite::Anchor;
+use bevy::utils::HashMap;
 use bevy::{prelude::*, window::PrimaryWindow};
 use bevy_rapier2d::prelude::*;
 use kira::sound::static_sound::{StaticSoundHandle, StaticSoundSettings};
 use rand::Rng;
 use std::time::Duration;
 
-#[cfg(not(target_arch = "wasm32"))]
-use bevy_hanabi::*;
-
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
-use super::{components::*, PLAYER_SPEED};
-use super::{PlayerState, SPACESHIP_SIZE};
+use super::resources::PlayMode;
+use super::{components::*, ENGINE_THRUST_STEP, ENGINE_VOLUME, PLAYER_SPEED};
+use super::{
+    PlayerState, PICKUP_RADIUS, PLAYER_LINEAR_DAMPING, PLAYER_MAX_HEALTH,
+    SPACESHIP_SIZE,
+};
 use crate::audio::assets::AudioSource;
-use crate::audio::resources::{KiraManager, SamplePack};
+use crate::audio::resources::{KiraManager, SamplePack, Sfx};
+use crate::controls::resources::ActionState;
 use crate::events::{GameOver, PlayerHit};
 use crate::game::components::Wall;
+use crate::game::effects::components::StatusEffects;
 use crate::game::enemy::components::*;
-use crate::game::fish::FISH_SIZE;
 use crate::game::gui::components::Avatar;
 use crate::game::regeneration::RegeneratePlayerEvent;
+use crate::game::resources::Arena;
 use crate::game::score::resources::Score;
 use crate::game::score::ScoreUpdateEvent;
+use crate::game::shield::components::Shield;
+use crate::game::shield::{ShieldBrokenEvent, SHIELD_BREAK_INVULNERABILITY};
+use crate::game::water_gun::components::WaterGun;
 use crate::helper_functions::*;
-use crate::resources::TextureStorage;
+use crate::particles::components::{ParticleEffectKind, ParticleEmitter};
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-#[cfg(not(target_arch = "wasm32"))]
 pub fn spawn_player(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
     asset_server: Res<AssetServer>,
-    texture_storage: Res<TextureStorage>,
-    mut effects: ResMut<Assets<EffectAsset>>,
+    play_mode: Res<PlayMode>,
 ) {
     // Assume that there can be only one entity of PrimaryWindow at the time
     let window = window_query.get_single().unwrap();
+    let center = arena.rect(window).center();
 
-    // Prepare engine particles effect
-    let mut color_gradient = Gradient::new();
-    color_gradient.add_key(0.0, Vec4::new(0., 0.07, 0.06, 0.0));
-    color_gradient.add_key(0.2, Vec4::new(0.06, 0.02, 0.10, 0.5));
-    color_gradient.add_key(1.0, Vec4::new(0., 0., 0., 0.));
-
-    let engine_effect = effects.add(
-        EffectAsset {
-            name: "RocketFlame".to_string(),
-            capacity: 1000,
-            spawner: Spawner::rate(70.0.into()).with_starts_active(false),
-            ..default()
-        }
-        .init(InitLifetimeModifier {
-            lifetime: 3_f32.into(),
-        })
-        .init(InitPositionCircleModifier {
-            radius: 11.,
-            ..default()
-        })
-        .init(InitVelocityCircleModifier {
-            axis: Vec3::Z,
-            speed: 30.0.into(),
-            ..default()
-        })
-        .init(InitVelocityTangentModifier {
-            speed: Value::Uniform((-20., 20.)),
-            axis: Vec3::Z,
-            ..default()
-        })
-        .render(ParticleTextureModifier {
-            texture: texture_storage.smoke.clone_weak(),
-        })
-        .render(SizeOverLifetimeModifier {
-            gradient: Gradient::constant(Vec2::splat(25.0)),
-        })
-        .render(ColorOverLifetimeModifier {
-            gradient: color_gradient,
-        }),
-    );
-
-    commands
-        .spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    custom_size: Some(Vec2::splat(SPACESHIP_SIZE)),
-                    ..default()
-                },
-                transform: Transform::from_xyz(
-                    window.width() / 2.,
-                    window.height() / 2.,
-                    10.,
-                ),
-                texture: asset_server.load("sprites/Cat's starship.png"),
-                ..default()
-            },
-            RigidBody::Dynamic,
-            Collider::ball(SPACESHIP_SIZE / 2.),
-            ExternalForce {
-                force: Vec2::ZERO,
-                torque: 0.,
-            },
-            Damping {
-                linear_damping: 0.6,
-                angular_damping: 5.,
-            },
-            ActiveCollisionTypes::all(),
-            ActiveEvents::COLLISION_EVENTS,
-            Restitution::coefficient(1.),
-            Player { health: 3 },
-            Avatar(asset_server.load("sprites/Avatars/Frame Kitty.png")),
-            Name::new("Kitty"),
-        ))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            custom_size: Some(Vec2::new(53., 29.) / 1.5),
-                            anchor: Anchor::Custom(Vec2::new(0., 1.6)),
-                            ..default()
-                        },
-                        transform: Transform::from_xyz(0., -1.5, -1.),
-                        texture: asset_server
-                            .load("sprites/Rocket engine.png")
-                            .into(),
-
-                        ..default()
-                    },
-                    RocketEngineSprite,
-                ))
-                .with_children(|parent| {
-                    parent.spawn((
-                        RocketEngineParticles,
-                        ParticleEffectBundle {
-                            transform: Transform::from_translation(
-                                (Vec2::NEG_Y * 33.).extend(0.),
-                            ),
-                            effect: ParticleEffect::new(engine_effect)
-                                .with_z_layer_2d(Some(0.)),
-                            ..default()
-                        },
-                        Name::new("RocketEngineParticles"),
-                    ));
-                });
-        });
-}
-
-#[cfg(target_arch = "wasm32")]
-pub fn spawn_player_without_gpu_particles(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-) {
-    // Assume that there can be only one entity of PrimaryWindow at the time
-    let window = window_query.get_single().unwrap();
-
-    commands
-        .spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    custom_size: Some(Vec2::splat(SPACESHIP_SIZE)),
-                    ..default()
-                },
-                transform: Transform::from_xyz(
-                    window.width() / 2.,
-                    window.height() / 2.,
-                    10.,
-                ),
-                texture: asset_server.load("sprites/Cat's starship.png"),
-                ..default()
-            },
-            RigidBody::Dynamic,
-            Collider::ball(SPACESHIP_SIZE / 2.),
-            ExternalForce {
-                force: Vec2::ZERO,
-                torque: 0.,
-            },
-            Damping {
-                linear_damping: 0.6,
-                angular_damping: 5.,
-            },
-            ActiveCollisionTypes::all(),
-            ActiveEvents::COLLISION_EVENTS,
-            Restitution::coefficient(1.),
-            Player { health: 3 },
-            Avatar(asset_server.load("sprites/Avatars/Frame Kitty.png")),
-            Name::new("Kitty"),
-        ))
-        .with_children(|parent| {
-            parent.spawn((
+    let players = play_mode.players();
+    for id in players {
+        commands
+            .spawn((
                 SpriteBundle {
                     sprite: Sprite {
-                        custom_size: Some(Vec2::new(53., 29.) / 1.5),
-                        anchor: Anchor::Custom(Vec2::new(0., 1.6)),
+                        custom_size: Some(Vec2::splat(SPACESHIP_SIZE)),
+                        color: id.color(),
                         ..default()
                     },
-                    transform: Transform::from_xyz(0., -1.5, -1.),
-                    texture: asset_server
-                        .load("sprites/Rocket engine.png")
-                        .into(),
-
+                    transform: Transform::from_xyz(
+                        center.x + spawn_offset(*id, players.len()),
+                        center.y,
+                        10.,
+                    ),
+                    texture: asset_server.load("sprites/Cat's starship.png"),
                     ..default()
                 },
-                RocketEngineSprite,
-            ));
-        });
+                RigidBody::Dynamic,
+                Collider::ball(SPACESHIP_SIZE / 2.),
+                ExternalForce {
+                    force: Vec2::ZERO,
+                    torque: 0.,
+                },
+                Velocity::zero(),
+                Damping {
+                    linear_damping: PLAYER_LINEAR_DAMPING,
+                    angular_damping: 5.,
+                },
+                ActiveCollisionTypes::all(),
+                ActiveEvents::COLLISION_EVENTS,
+                Restitution::coefficient(1.),
+                Player::new(PLAYER_MAX_HEALTH),
+                WaterGun::default(),
+                StatusEffects::default(),
+                Avatar(asset_server.load(id.avatar_path())),
+                Name::new(id.name()),
+            ))
+            .insert((*id, PlayerState::default()))
+            .with_children(|parent| {
+                spawn_pickup_area(parent);
+                parent
+                    .spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::new(53., 29.) / 1.5),
+                                anchor: Anchor::Custom(Vec2::new(0., 1.6)),
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(0., -1.5, -1.),
+                            texture: asset_server
+                                .load("sprites/Rocket engine.png")
+                                .into(),
+
+                            ..default()
+                        },
+                        RocketEngineSprite,
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            RocketEngineParticles,
+                            ParticleEmitter::new(
+                                ParticleEffectKind::RocketFlame,
+                            ),
+                            SpatialBundle::from_transform(
+                                Transform::from_translation(
+                                    (Vec2::NEG_Y * 33.).extend(0.),
+                                ),
+                            ),
+                            Name::new("RocketEngineParticles"),
+                        ));
+                    });
+            });
+    }
 }
 
 pub fn despawn_player_on_exit_game_state(
     mut commands: Commands,
     player_query: Query<Entity, With<Player>>,
-    mut player_state: ResMut<NextState<PlayerState>>,
 ) {
-    if let Ok(player) = player_query.get_single() {
+    for player in player_query.iter() {
         despawn_player(&mut commands, player);
-        player_state.set(PlayerState::Vulnerable);
     }
 }
 
-pub fn despawn_player(commands: &mut Commands, player: Entity) {
-    commands.entity(player).despawn_recursive();
+/// Kitties of a co-op run start side by side around the center.
+fn spawn_offset(id: PlayerId, players_count: usize) -> f32 {
+    let shift = id.index() as f32 - (players_count - 1) as f32 / 2.;
+    shift * SPACESHIP_SIZE * 2.
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-pub fn player_movement(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<(&mut ExternalForce, &Transform), With<Player>>,
-    mut spawner_query: Query<&mut EffectSpawner, With<RocketEngineParticles>>,
-    time: Res<Time>,
-    mut rocket_transform_query: Query<
-        &mut Transform,
-        (With<RocketEngineSprite>, Without<Player>),
-    >,
-    mut kira_manager: NonSendMut<KiraManager>,
-    audio_assets: Res<Assets<AudioSource>>,
-    sample_pack: Res<SamplePack>,
-    mut local_is_playing: Local<bool>,
-    mut local_engine_handle: Local<Option<StaticSoundHandle>>,
-) {
-    if let Ok((mut player, player_transform)) = player_query.get_single_mut() {
-        let mut direction = Vec2::ZERO;
+/// Sensor which collects pickups, its radius follows Kitty's status
+/// effects.
+fn spawn_pickup_area(parent: &mut ChildBuilder) {
+    parent.spawn((
+        TransformBundle::default(),
+        Sensor,
+        Collider::ball(PICKUP_RADIUS),
+        // Must not change Kitty's mass
+        ColliderMassProperties::Density(0.),
+        ActiveEvents::COLLISION_EVENTS,
+        PickupArea,
+        Name::new("PickupArea"),
+    ));
+}
 
-        let up = KeyCode::W;
-        let down = KeyCode::S;
-        let left = KeyCode::A;
-        let right = KeyCode::D;
+/// Kitty whose pickup area touches `item`, if any.
+pub fn find_picker(
+    rapier_context: &RapierContext,
+    item: Entity,
+    pickup_area_query: &Query<(Entity, &Parent), With<PickupArea>>,
+) -> Option<Entity> {
+    pickup_area_query.iter().find_map(|(area, parent)| {
+        rapier_context
+            .intersection_pair(item, area)
+            .is_some_and(|v| v)
+            .then(|| parent.get())
+    })
+}
 
-        let up_opt = KeyCode::Up;
-        let down_opt = KeyCode::Down;
-        let left_opt = KeyCode::Left;
-        let right_opt = KeyCode::Right;
+pub fn despawn_player(commands: &mut Commands, player: Entity) {
+    commands.entity(player).despawn_recursive();
+}
 
-        if keyboard_input.pressed(left) || keyboard_input.pressed(left_opt) {
-            direction += Vec2::new(-1., 0.);
-        }
-        if keyboard_input.pressed(right) || keyboard_input.pressed(right_opt) {
-            direction += Vec2::new(1., 0.);
-        }
-        if keyboard_input.pressed(up) || keyboard_input.pressed(up_opt) {
-            direction += Vec2::new(0., 1.);
-        }
-        if keyboard_input.pressed(down) || keyboard_input.pressed(down_opt) {
-            direction += Vec2::new(0., -1.);
-        }
+/// Engine sounds quieter when gamepad stick is tilted only slightly.
+fn engine_volume(thrust: f32) -> f64 {
+    ENGINE_VOLUME * thrust.clamp(0.3, 1.) as f64
+}
 
-        // If there are some input
-        if direction.length() > 0.0 {
-            direction = direction.normalize();
-            // Animate engine rotation
-            rotate_transform_with_parent_calibration(
-                &player_transform.rotation,
-                &mut rocket_transform_query.single_mut(),
-                direction * -1.,
-                // Our sprite was drawn in this axis
-                Vec2::NEG_Y,
-                Some(&time),
-            );
+/// Engine sound of one Kitty, plays while the Kitty thrusts.
+pub struct EngineSound {
+    handle: StaticSoundHandle,
+    thrust: f32,
+}
 
-            // Play engine audio
+/// Starts engine sound when Kitty starts thrusting, follows analog thrust
+/// changes and fades the sound out when thrust stops.
+fn play_engine_sound(
+    engine: &mut Option<EngineSound>,
+    thrust: f32,
+    kira_manager: &mut KiraManager,
+    audio_assets: &Assets<AudioSource>,
+    sample_pack: &SamplePack,
+) {
+    if thrust > 0. {
+        match engine {
             // Button was just pressed
-            if !*local_is_playing {
+            None => {
                 let rand_pos = rand::thread_rng().gen_range(0.0..3.0);
                 let sample = audio_assets
                     .get(&sample_pack.engine)
@@ -290,15 +204,12 @@ pub fn player_movement(
                             .volume(0.01)
                             .output_destination(kira_manager.get_master()),
                     );
-                sample
-                    .settings
-                    .output_destination(kira_manager.get_master());
                 let mut handle = kira_manager.play(sample).unwrap();
                 // For playing from rand position
                 handle.seek_to(rand_pos).unwrap();
                 handle
                     .set_volume(
-                        0.21,
+                        engine_volume(thrust),
                         kira::tween::Tween {
                             duration: Duration::from_millis(100),
                             ..default()
@@ -306,506 +217,373 @@ pub fn player_movement(
                     )
                     .unwrap();
                 handle.set_loop_region(..).unwrap();
-                *local_is_playing = true;
-
-                *local_engine_handle = Some(handle);
+                *engine = Some(EngineSound { handle, thrust });
             }
-        } else {
-            // Stop only if already playing
-            if *local_is_playing {
-                if let Some(ref mut handle) = *local_engine_handle {
-                    if let Err(e) = handle.stop(kira::tween::Tween {
-                        duration: Duration::from_secs(1),
-                        easing: kira::tween::Easing::OutPowf(1.),
+            // Follow analog thrust changes
+            Some(engine)
+                if (thrust - engine.thrust).abs() > ENGINE_THRUST_STEP =>
+            {
+                if let Err(e) = engine.handle.set_volume(
+                    engine_volume(thrust),
+                    kira::tween::Tween {
+                        duration: Duration::from_millis(100),
                         ..default()
-                    }) {
-                        println!("Error engine sound stopping: {}", e);
-                    }
-                    *local_is_playing = false;
+                    },
+                ) {
+                    println!("Error engine sound volume changing: {}", e);
                 }
+                engine.thrust = thrust;
             }
+            Some(_) => {}
         }
-
-        player.force = direction * PLAYER_SPEED * time.delta_seconds();
-
-        if let Ok(mut spawner) = spawner_query.get_single_mut() {
-            spawner.set_active(direction.length() > 0.0);
-        }
-    } else {
-        if *local_is_playing {
-            if let Some(ref mut handle) = *local_engine_handle {
-                if let Err(e) = handle.stop(kira::tween::Tween {
-                    duration: Duration::from_secs(1),
-                    easing: kira::tween::Easing::OutPowf(1.),
-                    ..default()
-                }) {
-                    println!("Error engine sound stopping: {}", e);
-                }
-                *local_is_playing = false;
-            }
+    } else if let Some(mut engine) = engine.take() {
+        // Stop only if already playing
+        if let Err(e) = engine.handle.stop(kira::tween::Tween {
+            duration: Duration::from_secs(1),
+            easing: kira::tween::Easing::OutPowf(1.),
+            ..default()
+        }) {
+            println!("Error engine sound stopping: {}", e);
         }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-pub fn player_movement_without_gpu_particles(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<(&mut ExternalForce, &Transform), With<Player>>,
-    time: Res<Time>,
-    mut rocket_transform_query: Query<
-        &mut Transform,
-        (With<RocketEngineSprite>, Without<Player>),
+type RocketEngineQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static Parent, &'static mut Transform),
+    (With<RocketEngineSprite>, Without<Player>),
+>;
+
+pub fn player_movement(
+    action_state: Res<ActionState>,
+    mut player_query: Query<
+        (
+            Entity,
+            &PlayerId,
+            &mut ExternalForce,
+            &Transform,
+            &StatusEffects,
+        ),
+        With<Player>,
+    >,
+    mut emitter_query: Query<
+        (&Parent, &mut ParticleEmitter),
+        With<RocketEngineParticles>,
     >,
+    time: Res<Time>,
+    mut rocket_transform_query: RocketEngineQuery,
     mut kira_manager: NonSendMut<KiraManager>,
     audio_assets: Res<Assets<AudioSource>>,
     sample_pack: Res<SamplePack>,
-    mut local_is_playing: Local<bool>,
-    mut local_engine_handle: Local<Option<StaticSoundHandle>>,
-    asset_server: Res<AssetServer>,
-    mut commands: Commands,
+    mut local_engines: Local<HashMap<PlayerId, Option<EngineSound>>>,
 ) {
-    if let Ok((mut player, player_transform)) = player_query.get_single_mut() {
-        let mut direction = Vec2::ZERO;
-
-        let up = KeyCode::W;
-        let down = KeyCode::S;
-        let left = KeyCode::A;
-        let right = KeyCode::D;
-
-        let up_opt = KeyCode::Up;
-        let down_opt = KeyCode::Down;
-        let left_opt = KeyCode::Left;
-        let right_opt = KeyCode::Right;
-
-        if keyboard_input.pressed(left) || keyboard_input.pressed(left_opt) {
-            direction += Vec2::new(-1., 0.);
-        }
-        if keyboard_input.pressed(right) || keyboard_input.pressed(right_opt) {
-            direction += Vec2::new(1., 0.);
-        }
-        if keyboard_input.pressed(up) || keyboard_input.pressed(up_opt) {
-            direction += Vec2::new(0., 1.);
-        }
-        if keyboard_input.pressed(down) || keyboard_input.pressed(down_opt) {
-            direction += Vec2::new(0., -1.);
-        }
+    for (player, id, mut force, player_transform, effects) in
+        player_query.iter_mut()
+    {
+        let direction = action_state.thrust(*id);
+        let Some((engine, _, mut engine_transform)) = rocket_transform_query
+            .iter_mut()
+            .find(|(_, parent, _)| parent.get() == player)
+        else {
+            continue;
+        };
 
         // If there are some input
         if direction.length() > 0.0 {
-            direction = direction.normalize();
             // Animate engine rotation
             rotate_transform_with_parent_calibration(
                 &player_transform.rotation,
-                &mut rocket_transform_query.single_mut(),
+                &mut engine_transform,
                 direction * -1.,
                 // Our sprite was drawn in this axis
                 Vec2::NEG_Y,
                 Some(&time),
             );
-
-            // Play engine audio
-            // Button was just pressed
-            if !*local_is_playing {
-                let rand_pos = rand::thread_rng().gen_range(0.0..3.0);
-                let sample = audio_assets
-                    .get(&sample_pack.engine)
-                    .unwrap()
-                    .get()
-                    .with_settings(
-                        StaticSoundSettings::new()
-                            .volume(0.01)
-                            .output_destination(kira_manager.get_master()),
-                    );
-                sample
-                    .settings
-                    .output_destination(kira_manager.get_master());
-                let mut handle = kira_manager.play(sample).unwrap();
-                // For playing from rand position
-                handle.seek_to(rand_pos).unwrap();
-                handle
-                    .set_volume(
-                        0.21,
-                        kira::tween::Tween {
-                            duration: Duration::from_millis(100),
-                            ..default()
-                        },
-                    )
-                    .unwrap();
-                handle.set_loop_region(..).unwrap();
-                *local_is_playing = true;
-
-                *local_engine_handle = Some(handle);
-            }
-        } else {
-            // Stop only if already playing
-            if *local_is_playing {
-                if let Some(ref mut handle) = *local_engine_handle {
-                    if let Err(e) = handle.stop(kira::tween::Tween {
-                        duration: Duration::from_secs(1),
-                        easing: kira::tween::Easing::OutPowf(1.),
-                        ..default()
-                    }) {
-                        println!("Error engine sound stopping: {}", e);
-                    }
-                    *local_is_playing = false;
-                }
-            }
         }
-
-        player.force = direction * PLAYER_SPEED * time.delta_seconds();
-
-        // Engine particles
-        if direction.length() > 0.0 {
-            let mut rng = rand::thread_rng();
-
-            let direction = direction
-                .rotated(std::f32::consts::PI)
-                .rotated(rng.gen_range(-0.3..0.3));
-            let velocity = rng.gen_range(100.0..200.0);
-            let timer = Timer::from_seconds(1.9, TimerMode::Once);
-            let position = (player_transform.translation.truncate()
-                + direction * 30.
-                + Vec2::new(rng.gen(), rng.gen()).normalize() * 20.)
-                .extend(player_transform.translation.z - 2.);
-
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        custom_size: Some(FISH_SIZE),
-                        color: Color::rgba(0.2, 0.16, 0.29, 0.),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(position)
-                        .with_rotation(Quat::from_rotation_z(rng.gen())),
-                    texture: asset_server.load("sprites/Smoke.png"),
-                    ..default()
-                },
-                SmokeParticle {
-                    direction,
-                    velocity,
-                    timer,
-                },
-            ));
-        }
-    } else {
-        if *local_is_playing {
-            if let Some(ref mut handle) = *local_engine_handle {
-                if let Err(e) = handle.stop(kira::tween::Tween {
-                    duration: Duration::from_secs(1),
-                    easing: kira::tween::Easing::OutPowf(1.),
-                    ..default()
-                }) {
-                    println!("Error engine sound stopping: {}", e);
-                }
-                *local_is_playing = false;
+        play_engine_sound(
+            local_engines.entry(*id).or_default(),
+            direction.length(),
+            &mut kira_manager,
+            &audio_assets,
+            &sample_pack,
+        );
+
+        force.force = direction
+            * PLAYER_SPEED
+            * effects.modifiers().thrust
+            * time.delta_seconds();
+
+        let thrusting = direction.length() > 0.0;
+        for (parent, mut emitter) in emitter_query.iter_mut() {
+            if parent.get() == engine && emitter.active != thrusting {
+                emitter.active = thrusting;
             }
         }
     }
-}
-
-pub fn spawn_particles_on_collision_with_enemy(
-    mut commands: Commands,
-    mut hit_events: EventReader<PlayerHit>,
-    asset_server: Res<AssetServer>,
-) {
-    if let Some(event) = hit_events.iter().next() {
-        let mut rng = rand::thread_rng();
-
-        for _ in 0..event.drop_count {
-            let direction = event
-                .hit_normal
-                .rotated(std::f32::consts::PI)
-                .rotated(rng.gen_range(-0.9..0.9));
-            let velocity = rng.gen_range(200.0..300.0);
-            let timer = Timer::from_seconds(3., TimerMode::Once);
-            let position = (event.position.truncate()
-                + Vec2::new(rng.gen(), rng.gen()).normalize() * 20.)
-                .extend(event.position.z);
-
-            commands.spawn((
-                SpriteBundle {
-                    sprite: Sprite {
-                        custom_size: Some(FISH_SIZE),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(position)
-                        .with_rotation(Quat::from_rotation_z(rng.gen())),
-                    texture: asset_server.load("sprites/Fish.png"),
-                    ..default()
-                },
-                DropFishParticle {
-                    direction,
-                    velocity,
-                    timer,
-                },
-            ));
-        }
-    }
-}
 
-pub fn poll_and_despawn_collision_particles(
-    mut commands: Commands,
-    mut particles_query: Query<(
-        Entity,
-        &mut Sprite,
-        &mut Transform,
-        &mut DropFishParticle,
-    )>,
-    time: Res<Time>,
-) {
-    for (entity, mut sprite, mut transform, mut particle) in
-        particles_query.iter_mut()
-    {
-        if particle.timer.tick(time.delta()).finished() {
-            commands.entity(entity).despawn();
-        } else {
-            let x =
-                particle.direction.x * time.delta_seconds() * particle.velocity;
-            let y =
-                particle.direction.y * time.delta_seconds() * particle.velocity;
-
-            transform.translation.x += x;
-            transform.translation.y += y;
-            sprite.color.set_a(particle.timer.percent_left());
-            particle.velocity = particle.velocity - time.delta_seconds() * 87.;
+    // Silence engines of Kitties which are out
+    for (id, engine) in local_engines.iter_mut() {
+        if !player_query
+            .iter()
+            .any(|(_, player_id, ..)| player_id == id)
+        {
+            play_engine_sound(
+                engine,
+                0.,
+                &mut kira_manager,
+                &audio_assets,
+                &sample_pack,
+            );
         }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-pub fn poll_and_despawn_smoke_particles(
-    mut commands: Commands,
-    mut particles_query: Query<(
+type CollidingPlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
         Entity,
-        &mut Sprite,
-        &mut Transform,
-        &mut SmokeParticle,
-    )>,
-    time: Res<Time>,
-) {
-    for (entity, mut sprite, mut transform, mut particle) in
-        particles_query.iter_mut()
-    {
-        if particle.timer.tick(time.delta()).finished() {
-            commands.entity(entity).despawn();
-        } else {
-            let x =
-                particle.direction.x * time.delta_seconds() * particle.velocity;
-            let y =
-                particle.direction.y * time.delta_seconds() * particle.velocity;
-
-            transform.translation.x += x;
-            transform.translation.y += y;
-            let diff = 1.0 - particle.timer.percent_left();
-            let alpha = if diff < 0.5 {
-                diff
-            } else {
-                particle.timer.percent_left()
-            };
-            sprite.color.set_a(alpha);
-            sprite.color.set_r(particle.timer.percent_left() * 0.5);
-            sprite.color.set_g(particle.timer.percent_left() * 0.1);
-            particle.velocity = particle.velocity - time.delta_seconds() * 87.;
-        }
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
-pub fn despawn_smoke_particles_on_exit_state(
-    mut commands: Commands,
-    particles_query: Query<Entity, With<SmokeParticle>>,
-) {
-    for entity in particles_query.iter() {
-        commands.entity(entity).despawn();
-    }
-}
-
-pub fn despawn_collision_particles(
-    mut commands: Commands,
-    particles_query: Query<Entity, With<DropFishParticle>>,
-) {
-    for particle in particles_query.iter() {
-        commands.entity(particle).despawn();
-    }
+        &'static PlayerId,
+        &'static Name,
+        &'static GlobalTransform,
+        &'static mut Player,
+        &'static mut PlayerState,
+    ),
+    Without<Enemy>,
+>;
+
+/// Events which a dog hit may cause.
+#[derive(SystemParam)]
+pub struct HitEvents<'w> {
+    game_over: EventWriter<'w, GameOver>,
+    player_hit: EventWriter<'w, PlayerHit>,
+    shield_broken: EventWriter<'w, ShieldBrokenEvent>,
+    score_update: EventWriter<'w, ScoreUpdateEvent>,
 }
 
 pub fn handle_player_collision(
-    state: Res<State<PlayerState>>,
     mut commands: Commands,
     mut collision_events: EventReader<CollisionEvent>,
     enemies: Query<(Entity, &GlobalTransform), With<Enemy>>,
-    walls: Query<Entity, With<Wall>>,
-    mut player_query: Query<
-        (Entity, &GlobalTransform, &mut Player),
-        Without<Enemy>,
-    >,
-    mut player_state: ResMut<NextState<PlayerState>>,
-    mut kira_manager: NonSendMut<KiraManager>,
-    audio_assets: Res<Assets<AudioSource>>,
-    sample_pack: Res<SamplePack>,
-    mut game_over_event_writer: EventWriter<GameOver>,
-    mut event_writer: EventWriter<PlayerHit>,
-    mut score_events: EventWriter<ScoreUpdateEvent>,
+    mut player_query: CollidingPlayerQuery,
+    mut sfx: Sfx,
+    mut hit_events: HitEvents,
     mut score: ResMut<Score>,
 ) {
+    let mut players_left = player_query.iter().count();
     for event in collision_events.iter() {
-        if let Ok((player_entity, global_transform, mut player)) =
-            player_query.get_single_mut()
-        {
-            if let CollisionEvent::Started(entity1, entity2, _) = event {
-                let collided_with;
-
-                if player_entity == *entity1 {
-                    collided_with = *entity2;
-                } else if player_entity == *entity2 {
-                    collided_with = *entity1;
+        if let CollisionEvent::Started(entity1, entity2, _) = event {
+            let (player_entity, collided_with) =
+                if player_query.contains(*entity1) {
+                    (*entity1, *entity2)
+                } else if player_query.contains(*entity2) {
+                    (*entity2, *entity1)
                 } else {
                     continue;
-                }
-
-                if let Some((_, enemy_global_transform)) =
-                    enemies.iter().find(|(e, _)| {
-                        *e == collided_with
-                        // A little bit strange, but whatever
-                            && state.0 == PlayerState::Vulnerable
-                    })
-                {
-                    // Collision
-                    if player.health > 1 {
-                        player.health -= 1;
-
-                        // Play alarm sound
-                        let sound_data = audio_assets
-                            .get(&sample_pack.alarm)
-                            .unwrap()
-                            .get()
-                            .with_settings(
-                                StaticSoundSettings::new()
-                                    .volume(0.5)
-                                    .output_destination(
-                                        kira_manager.get_master(),
-                                    ),
-                            );
-                        kira_manager.play(sound_data).unwrap();
-
-                        // Play meow sound
-                        let sound_data = audio_assets
-                            .get(get_random_meow(&sample_pack))
-                            .unwrap()
-                            .get()
-                            .with_settings(
-                                StaticSoundSettings::new()
-                                    .volume(0.8)
-                                    .output_destination(
-                                        kira_manager.get_master(),
-                                    ),
-                            );
-                        kira_manager.play(sound_data).unwrap();
-
-                        // Spawn Timer to Player entity
-                        commands.entity(player_entity).insert(
-                            PlayerInvulnerableTimer(Timer::from_seconds(
-                                3.,
-                                TimerMode::Once,
-                            )),
-                        );
-                        player_state.set(PlayerState::Invulnerable);
-                    } else {
-                        player.health -= 1;
-
-                        despawn_player(&mut commands, player_entity);
+                };
+            let Ok((_, id, name, global_transform, mut player, mut state)) =
+                player_query.get_mut(player_entity)
+            else {
+                continue;
+            };
+            // Kitty is already out, it's despawned at the end of the frame
+            if player.health == 0 {
+                continue;
+            }
 
-                        game_over_event_writer.send(GameOver);
-                    }
-                    // Remove 25% from kitty's score
-                    let drop_count = match score.drop_score(player_entity, 0.1)
-                    {
-                        Ok(score) => {
-                            score_events.send(ScoreUpdateEvent {
-                            name: Name::from("Kitty"),
-                            event_type:
-                                crate::game::score::ScoreEventType::ScoreDrop(
-                                    score,
+            if *state == PlayerState::Shielded
+                && enemies.contains(collided_with)
+            {
+                // Shield takes the hit, Kitty keeps health and crackers
+                hit_events.shield_broken.send(ShieldBrokenEvent {
+                    player: player_entity,
+                });
+                commands
+                    .entity(player_entity)
+                    .insert(PlayerInvulnerableTimer(Timer::from_seconds(
+                        SHIELD_BREAK_INVULNERABILITY,
+                        TimerMode::Once,
+                    )));
+                *state = PlayerState::Invulnerable;
+            } else if let Some((_, enemy_global_transform)) =
+                enemies.iter().find(|(e, _)| {
+                    *e == collided_with
+                    // A little bit strange, but whatever
+                        && *state == PlayerState::Vulnerable
+                })
+            {
+                // Collision
+                if player.health > 1 {
+                    player.health -= 1;
+
+                    // Play alarm sound
+                    let sound_data = sfx
+                        .audio_assets
+                        .get(&sfx.sample_pack.alarm)
+                        .unwrap()
+                        .get()
+                        .with_settings(
+                            StaticSoundSettings::new()
+                                .volume(0.5)
+                                .output_destination(
+                                    sfx.kira_manager.get_master(),
                                 ),
-                        }
                         );
-                            score
-                        }
-                        Err(_) => panic!("No score for Kitty!"),
-                    };
-                    // Write event with collision data
-                    let hit_normal =
-                        (enemy_global_transform.translation().truncate()
-                            - global_transform.translation().truncate())
-                        .normalize();
-                    let position = global_transform.translation();
-                    event_writer.send(PlayerHit {
-                        remaining_health: player.health,
-                        position,
-                        hit_normal,
-                        drop_count,
-                    });
-                } else if walls.iter().any(|e| collided_with == e) {
+                    sfx.kira_manager.play(sound_data).unwrap();
+
                     // Play meow sound
-                    let sound_data = audio_assets
-                        .get(&sample_pack.meow5)
+                    let sound_data = sfx
+                        .audio_assets
+                        .get(get_random_meow(&sfx.sample_pack))
                         .unwrap()
                         .get()
                         .with_settings(
                             StaticSoundSettings::new()
-                                .volume(0.23)
-                                .output_destination(kira_manager.get_master()),
+                                .volume(0.8)
+                                .output_destination(
+                                    sfx.kira_manager.get_master(),
+                                ),
                         );
+                    sfx.kira_manager.play(sound_data).unwrap();
+
+                    // Spawn Timer to Player entity
+                    commands.entity(player_entity).insert(
+                        PlayerInvulnerableTimer(Timer::from_seconds(
+                            3.,
+                            TimerMode::Once,
+                        )),
+                    );
+                    *state = PlayerState::Invulnerable;
+                } else {
+                    player.health -= 1;
 
-                    kira_manager.play(sound_data).unwrap();
+                    despawn_player(&mut commands, player_entity);
+
+                    // Run ends when the last Kitty is out
+                    players_left -= 1;
+                    if players_left == 0 {
+                        hit_events.game_over.send(GameOver);
+                    }
                 }
+                // Remove 10% from kitty's score
+                let drop_count = score.drop_on_hit(player_entity);
+                if drop_count > 0 {
+                    hit_events.score_update.send(ScoreUpdateEvent {
+                        name: name.clone(),
+                        event_type:
+                            crate::game::score::ScoreEventType::ScoreDrop(
+                                drop_count,
+                            ),
+                    });
+                }
+                // Write event with collision data
+                let hit_normal =
+                    (enemy_global_transform.translation().truncate()
+                        - global_transform.translation().truncate())
+                    .normalize();
+                let position = global_transform.translation();
+                hit_events.player_hit.send(PlayerHit {
+                    player: *id,
+                    remaining_health: player.health,
+                    position,
+                    hit_normal,
+                    drop_count,
+                });
             }
         }
     }
 }
 
+/// Kitty meows when it bumps into a wall.
+pub fn meow_on_wall_collision(
+    mut collision_events: EventReader<CollisionEvent>,
+    walls: Query<Entity, With<Wall>>,
+    player_query: Query<&Player>,
+    mut sfx: Sfx,
+) {
+    for event in collision_events.iter() {
+        if let CollisionEvent::Started(entity1, entity2, _) = event {
+            let (player, collided_with) = match player_query.get(*entity1) {
+                Ok(player) => (player, *entity2),
+                Err(_) => match player_query.get(*entity2) {
+                    Ok(player) => (player, *entity1),
+                    Err(_) => continue,
+                },
+            };
+            // Kitty is already out, it's despawned at the end of the frame
+            if player.health == 0 || !walls.contains(collided_with) {
+                continue;
+            }
+
+            // Play meow sound
+            let sound_data = sfx
+                .audio_assets
+                .get(&sfx.sample_pack.meow5)
+                .unwrap()
+                .get()
+                .with_settings(
+                    StaticSoundSettings::new()
+                        .volume(0.23)
+                        .output_destination(sfx.kira_manager.get_master()),
+                );
+
+            sfx.kira_manager.play(sound_data).unwrap();
+        }
+    }
+}
+
 pub fn count_player_invulnerability_timer(
     mut commands: Commands,
     time: Res<Time>,
-    mut player_state: ResMut<NextState<PlayerState>>,
     mut player_query: Query<
-        (Entity, &mut PlayerInvulnerableTimer),
+        (
+            Entity,
+            &mut PlayerInvulnerableTimer,
+            &mut PlayerState,
+            Option<&Shield>,
+        ),
         With<Player>,
     >,
 ) {
-    if let Ok((entity, mut timer)) = player_query.get_single_mut() {
+    for (entity, mut timer, mut state, shield) in player_query.iter_mut() {
         if timer.0.tick(time.delta()).finished() {
-            player_state.set(PlayerState::Vulnerable);
+            // Shield picked while invulnerable starts working now
+            *state = if shield.is_some() {
+                PlayerState::Shielded
+            } else {
+                PlayerState::Vulnerable
+            };
             commands.entity(entity).remove::<PlayerInvulnerableTimer>();
         }
     }
 }
 
+type EngineSpriteQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Parent, &'static mut Sprite),
+    (With<RocketEngineSprite>, Without<Player>),
+>;
+
 // TODO: We should make blinking in shader, for group transparecy animation.
 pub fn blink_player(
     mut player_query: Query<
-        (&mut Sprite, &PlayerInvulnerableTimer),
+        (Entity, &mut Sprite, &PlayerInvulnerableTimer),
         With<Player>,
     >,
-    mut engine_query: Query<
-        &mut Sprite,
-        (With<RocketEngineSprite>, Without<Player>),
-    >,
+    mut engine_query: EngineSpriteQuery,
 ) {
-    if let Ok((mut sprite, timer)) = player_query.get_single_mut() {
-        if timer.0.percent() >= 0.9 {
-            sprite.color.set_a(1.);
-            if let Ok(mut engine) = engine_query.get_single_mut() {
-                engine.color.set_a(1.);
-            }
+    for (player, mut sprite, timer) in player_query.iter_mut() {
+        let alpha = if timer.0.percent() >= 0.9 {
+            1.
         } else {
-            sprite
-                .color
-                .set_a((timer.0.elapsed_secs() * 8.).sin().abs());
-            if let Ok(mut engine) = engine_query.get_single_mut() {
-                engine
-                    .color
-                    .set_a((timer.0.elapsed_secs() * 8.).sin().abs());
+            (timer.0.elapsed_secs() * 8.).sin().abs()
+        };
+        sprite.color.set_a(alpha);
+        for (parent, mut engine) in engine_query.iter_mut() {
+            if parent.get() == player {
+                engine.color.set_a(alpha);
             }
         }
     }
@@ -815,8 +593,8 @@ pub fn regenerate_player(
     mut player_query: Query<&mut Player>,
     mut regen_events: EventReader<RegeneratePlayerEvent>,
 ) {
-    if let Some(event) = regen_events.iter().next() {
-        if let Ok(mut player) = player_query.get_single_mut() {
+    for event in regen_events.iter() {
+        if let Ok(mut player) = player_query.get_mut(event.player) {
             player.health = event.new_health;
         }
     }
@@ -2,19 +2,18 @@ use bevy::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
+use super::pickups::{
+    components::PickupKind,
+    resources::{PickupSpec, Trajectory},
+    RegisterPickup,
+};
 use crate::AppState;
 
-use self::resources::FlyingMilkResource;
-use self::systems::{
-    check_collision, cup_of_milk_movement, despawn_milk_on_exit_state,
-    despawn_milk_out_of_screen, spawn_milk_cup,
-};
+use self::systems::drink_milk;
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
 
 // Top-level modules
-mod components;
-mod resources;
 mod systems;
 
 // ───── Constants ────────────────────────────────────────────────────────── //
@@ -29,32 +28,38 @@ pub struct RegenerationPlugin;
 impl Plugin for RegenerationPlugin {
     fn build(&self, app: &mut App) {
         app
-            // Resources
-            .init_resource::<FlyingMilkResource>()
+            // Pickups
+            .register_pickup(
+                PickupKind::Milk,
+                PickupSpec {
+                    // Milk flies while any Kitty misses a heart
+                    wanted_by: |target| target.player.is_hurt(),
+                    spawn_delay: (0., MAX_SPAWN_TIME),
+                    trajectory: Trajectory {
+                        speed: MILK_SPEED,
+                        spin: 0.3,
+                        wild_spin: 7.,
+                        wild_spin_chance: 0.04,
+                    },
+                    texture: "sprites/A glass of milk.png",
+                    size: Vec2::new(210., 280.) / 7.,
+                    collider_radius: 145. / 7.,
+                    sound: |sample_pack| &sample_pack.milk,
+                    volume: 0.9,
+                    picked_message: Some("The milk'd been drinked!"),
+                    escaped_message: Some("The milk escaped!"),
+                },
+            )
             // Events
             .add_event::<RegeneratePlayerEvent>()
-            .add_event::<MilkEscapedEvent>()
             // Systems
-            .add_systems(
-                (
-                    spawn_milk_cup,
-                    cup_of_milk_movement,
-                    check_collision,
-                    despawn_milk_out_of_screen,
-                )
-                    .in_set(OnUpdate(AppState::Game)),
-            )
-            // Exit State Systems
-            .add_system(
-                despawn_milk_on_exit_state.in_schedule(OnExit(AppState::Game)),
-            );
+            .add_system(drink_milk.in_set(OnUpdate(AppState::Game)));
     }
 }
 
 // Events
 
 pub struct RegeneratePlayerEvent {
+    pub player: Entity,
     pub new_health: u8,
 }
-
-pub struct MilkEscapedEvent;
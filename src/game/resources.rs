@@ -1,8 +1,137 @@
 use bevy::prelude::*;
 
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::ARENA_SIZE;
+
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 #[derive(Resource, Default)]
 pub struct GameData {
     pub sheet_was_shown: bool,
 }
+
+/// Play area of the game, walls are built along its edges and the camera
+/// never looks past them.
+#[derive(Resource)]
+pub struct Arena {
+    pub size: Vec2,
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena { size: ARENA_SIZE }
+    }
+}
+
+impl Arena {
+    /// Arena bounds in world coordinates, the arena is never smaller than
+    /// the window.
+    pub fn rect(&self, window: &Window) -> Rect {
+        let window_size = Vec2::new(window.width(), window.height());
+        Rect::from_corners(Vec2::ZERO, self.size.max(window_size))
+    }
+
+    /// Radius of the circle around the arena, where dogs and pickups
+    /// start flying in from.
+    pub fn outskirts_radius(&self, window: &Window) -> f32 {
+        self.rect(window).half_size().length() * 1.5
+    }
+}
+
+/// Moves the camera center to `target` as close as possible, while the
+/// view with `half_view` extents stays inside of `arena`.
+pub fn clamp_view_center(target: Vec2, half_view: Vec2, arena: Rect) -> Vec2 {
+    let min = arena.min + half_view;
+    let max = arena.max - half_view;
+    // View wider than arena just looks at its center
+    let center = arena.center();
+    Vec2::new(
+        if min.x < max.x {
+            target.x.clamp(min.x, max.x)
+        } else {
+            center.x
+        },
+        if min.y < max.y {
+            target.y.clamp(min.y, max.y)
+        } else {
+            center.y
+        },
+    )
+}
+
+/// Camera center and zoom which keep every one of `targets` at least
+/// `margin` away from the edges of the view. View has `half_view` extents
+/// without zoom and never zooms out past the whole `arena`.
+pub fn frame_targets(
+    targets: &[Vec2],
+    half_view: Vec2,
+    margin: f32,
+    arena: Rect,
+) -> Option<(Vec2, f32)> {
+    let first = *targets.first()?;
+    let (min, max) =
+        targets.iter().fold((first, first), |(min, max), target| {
+            (min.min(*target), max.max(*target))
+        });
+
+    let needed = ((max - min) / 2. + Vec2::splat(margin)) / half_view;
+    let whole_arena = (arena.half_size() / half_view).max_element();
+    let zoom = needed.max_element().clamp(1., whole_arena.max(1.));
+
+    let center = clamp_view_center((min + max) / 2., half_view * zoom, arena);
+    Some((center, zoom))
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_stays_inside_of_arena() {
+        let arena = Rect::new(0., 0., 2000., 1000.);
+        let half_view = Vec2::new(400., 300.);
+
+        assert_eq!(
+            clamp_view_center(Vec2::new(1000., 500.), half_view, arena),
+            Vec2::new(1000., 500.)
+        );
+        assert_eq!(
+            clamp_view_center(Vec2::new(-50., 990.), half_view, arena),
+            Vec2::new(400., 700.)
+        );
+        assert_eq!(
+            clamp_view_center(
+                Vec2::new(1900., 10.),
+                Vec2::new(1200., 300.),
+                arena
+            ),
+            Vec2::new(1000., 300.)
+        );
+    }
+
+    #[test]
+    fn distant_kitties_stay_in_view() {
+        let arena = Rect::new(0., 0., 2400., 1600.);
+        let half_view = Vec2::new(580., 330.);
+        let margin = 100.;
+
+        // Close Kitties don't need any zoom
+        let close = [Vec2::new(1000., 800.), Vec2::new(1200., 800.)];
+        let (center, zoom) =
+            frame_targets(&close, half_view, margin, arena).unwrap();
+        assert_eq!(zoom, 1.);
+        assert_eq!(center, Vec2::new(1100., 800.));
+
+        // Kitties in opposite corners make the whole arena visible
+        let far = [Vec2::new(50., 50.), Vec2::new(2350., 1550.)];
+        let (center, zoom) =
+            frame_targets(&far, half_view, margin, arena).unwrap();
+        let view = Rect::from_center_half_size(center, half_view * zoom);
+        assert!(far.iter().all(|kitty| view.contains(*kitty)));
+
+        assert!(frame_targets(&[], half_view, margin, arena).is_none());
+    }
+}
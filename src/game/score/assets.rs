@@ -0,0 +1,165 @@
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::asset_loader::JsonAsset;
+use crate::game::enemy::components::DogType;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Game pacing table: when milestone messages fire, when dogs arrive and
+/// which dogs may arrive. Stage is chosen by the player's score.
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "5e0f3c2a-7d1b-4f8e-9a63-2b4c8d1e7f90"]
+pub struct ProgressionData {
+    pub stages: Vec<ProgressionStage>,
+}
+
+impl JsonAsset for ProgressionData {
+    const EXTENSIONS: &'static [&'static str] = &["progression.json"];
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProgressionStage {
+    /// Stage is active when score is equal or greater than this value.
+    pub from_score: u32,
+    pub messages: Trigger,
+    /// Dogs may arrive at any score, Kitty with too few crackers just drops
+    /// none of them on a hit.
+    pub dogs: Trigger,
+    /// Empty list means every dog type is eligible.
+    #[serde(default)]
+    pub dog_types: Vec<DogType>,
+    /// Scores on which the fish boss shows up.
+    #[serde(default)]
+    pub boss: Trigger,
+}
+
+/// Set of scores on which something happens.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Trigger {
+    #[serde(default)]
+    pub at: Vec<u32>,
+    #[serde(default)]
+    pub every: Option<u32>,
+}
+
+impl Trigger {
+    // `is_multiple_of` is too new for the toolchain the game supports
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn matches(&self, score: u32) -> bool {
+        self.at.contains(&score)
+            || self
+                .every
+                .is_some_and(|every| every > 0 && score % every == 0)
+    }
+}
+
+impl Default for ProgressionData {
+    /// Pacing used before the table is loaded.
+    fn default() -> Self {
+        ProgressionData {
+            stages: vec![ProgressionStage {
+                from_score: 0,
+                messages: Trigger {
+                    at: vec![1],
+                    every: Some(7),
+                },
+                dogs: Trigger {
+                    at: Vec::new(),
+                    every: Some(7),
+                },
+                dog_types: Vec::new(),
+                boss: Trigger::default(),
+            }],
+        }
+    }
+}
+
+impl ProgressionData {
+    pub fn stage(&self, score: u32) -> Option<&ProgressionStage> {
+        self.stages
+            .iter()
+            .filter(|stage| stage.from_score <= score)
+            .max_by_key(|stage| stage.from_score)
+    }
+
+    pub fn fires_message(&self, score: u32) -> bool {
+        self.stage(score)
+            .is_some_and(|stage| stage.messages.matches(score))
+    }
+
+    /// Returns eligible dog types if a dog should arrive on this score.
+    pub fn spawns_dog(&self, score: u32) -> Option<&[DogType]> {
+        self.stage(score)
+            .filter(|stage| stage.dogs.matches(score))
+            .map(|stage| stage.dog_types.as_slice())
+    }
+
+    pub fn spawns_boss(&self, score: u32) -> bool {
+        self.stage(score)
+            .is_some_and(|stage| stage.boss.matches(score))
+    }
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Entity;
+
+    use super::*;
+    use crate::game::score::resources::Score;
+
+    #[test]
+    fn default_pacing_matches_classic_rules() {
+        let data = ProgressionData::default();
+        assert!(data.fires_message(1));
+        assert!(data.fires_message(14));
+        assert!(!data.fires_message(8));
+        assert!(data.spawns_dog(7).is_some());
+        assert!(data.spawns_dog(1).is_none());
+        assert!(!data.spawns_boss(7));
+    }
+
+    #[test]
+    fn latest_reached_stage_is_used() {
+        let data: ProgressionData = serde_json::from_str(
+            r#"{
+                "stages": [
+                    { "from_score": 0, "messages": { "every": 5 },
+                      "dogs": { "at": [3] }, "dog_types": ["Ordinary"] },
+                    { "from_score": 10, "messages": { "every": 10 },
+                      "dogs": { "every": 4 }, "dog_types": ["BigBoy"],
+                      "boss": { "at": [20] } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(data.spawns_dog(3), Some(&[DogType::Ordinary][..]));
+        assert!(data.fires_message(5));
+        assert!(!data.fires_message(15));
+        assert_eq!(data.spawns_dog(12), Some(&[DogType::BigBoy][..]));
+        assert!(data.spawns_boss(20));
+        assert!(!data.spawns_boss(3));
+    }
+    #[test]
+    fn dogs_arriving_early_take_no_crackers() {
+        let data: ProgressionData = serde_json::from_str(
+            r#"{
+                "stages": [
+                    { "from_score": 0, "messages": {}, "dogs": { "at": [3] } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(data.spawns_dog(3).is_some());
+
+        let mut score = Score::default();
+        let kitty = Entity::from_raw(0);
+        score.add_score_to(&kitty, 3);
+        assert_eq!(score.drop_on_hit(kitty), 0);
+        assert_eq!(score.get_score(&kitty).unwrap(), 3);
+    }
+}
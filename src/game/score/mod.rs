@@ -2,12 +2,14 @@ use bevy::prelude::*;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
-use self::{resources::*, systems::*};
-use crate::AppState;
+use self::{assets::ProgressionData, resources::*, systems::*};
+use crate::{game::SimulationState, AppState};
 
 // ───── Submodules ───────────────────────────────────────────────────────── //
 
+pub mod assets;
 pub mod resources;
+pub mod storage;
 pub mod systems;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
@@ -17,14 +19,28 @@ pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app
+            // Assets
+            .add_asset::<ProgressionData>()
             // Events
             .add_event::<ScoreUpdateEvent>()
             // Resources
             .init_resource::<HighScores>()
             .init_resource::<Chart>()
+            .init_resource::<HighScoreStore>()
+            .init_resource::<RunTime>()
+            .init_resource::<Progression>()
             // Enter State Systems
             .add_system(insert_score.in_schedule(OnEnter(AppState::Game)))
+            .add_system(
+                store_run_highscores.in_schedule(OnEnter(AppState::GameOver)),
+            )
             // Systems
+            .add_system(
+                tick_run_time
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            )
+            .add_system(insert_player_scores.in_set(OnUpdate(AppState::Game)))
             .add_system(update_chart_data)
             .add_system(update_highscores)
             .add_system(
@@ -1,6 +1,28 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-use bevy::{prelude::*, reflect::Map, utils::HashMap};
+use bevy::{prelude::*, reflect::Map, time::Stopwatch, utils::HashMap};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::assets::ProgressionData;
+use super::storage::{
+    deserialize_entries, serialize_entries, HIGHSCORES_STORAGE_NAME,
+};
+use crate::game::player::components::PlayerId;
+use crate::storage::{default_backend, StorageBackend, StorageError};
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+/// How many entries the persistent high scores table keeps.
+pub const HIGHSCORES_LIMIT: usize = 20;
+
+/// Part of crackers Kitty drops when a dog hits it.
+pub const HIT_SCORE_DROP: f32 = 0.1;
+
+pub const PROGRESSION_PATH: &str = "json_data/default.progression.json";
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -16,12 +38,13 @@ pub struct Score {
 }
 
 impl Score {
-    pub fn add_score_to(&mut self, who: &Entity, how_much: u32) -> u32 {
+    /// Negative `how_much` takes score away, but never below zero.
+    pub fn add_score_to(&mut self, who: &Entity, how_much: i32) -> u32 {
         let old_score = match self.data.get(who) {
             Some(score) => *score,
             None => 0,
         };
-        let new_score = old_score + how_much;
+        let new_score = old_score.saturating_add_signed(how_much);
         self.data.insert(*who, new_score);
         new_score
     }
@@ -45,6 +68,12 @@ impl Score {
         Err(ScoreError::NoScoreForEntity(who))
     }
 
+    /// Crackers Kitty drops when a dog hits it. Kitty with too few of them
+    /// drops nothing.
+    pub fn drop_on_hit(&mut self, who: Entity) -> u32 {
+        self.drop_score(who, HIT_SCORE_DROP).unwrap_or(0)
+    }
+
     pub fn get_score(&self, for_who: &Entity) -> Result<u32, ScoreError> {
         match self.data.get(for_who) {
             Some(score) => Ok(*score),
@@ -53,10 +82,34 @@ impl Score {
     }
 }
 
-// For store on the disk
+/// Whom a score belongs to. Kitties are told apart by their id, dogs by
+/// their names.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ScoreOwner {
+    Player(PlayerId),
+    Dog(Name),
+}
+
+impl ScoreOwner {
+    pub fn name(&self) -> String {
+        match self {
+            ScoreOwner::Player(id) => id.name().to_string(),
+            ScoreOwner::Dog(name) => name.to_string(),
+        }
+    }
+
+    pub fn player(&self) -> Option<PlayerId> {
+        match self {
+            ScoreOwner::Player(id) => Some(*id),
+            ScoreOwner::Dog(_) => None,
+        }
+    }
+}
+
+// Scores of the current run
 #[derive(Resource, Debug)]
 pub struct HighScores {
-    pub scores: HashMap<Name, (Handle<Image>, u32)>,
+    pub scores: HashMap<ScoreOwner, (Handle<Image>, u32)>,
 }
 
 impl Default for HighScores {
@@ -67,18 +120,106 @@ impl Default for HighScores {
     }
 }
 
-impl HighScores {
-    pub fn sorted(
-        &self,
-    ) -> std::iter::Rev<std::vec::IntoIter<(u32, Name, Handle<Image>)>> {
-        let mut vec: Vec<(u32, Name, Handle<Image>)> = self
-            .scores
-            .iter()
-            .map(|(name, (image, score))| (*score, name.clone(), image.clone()))
-            .collect();
-        // vec.sort_by(|&(score1, _, _), &(score2, _, _)| score2.cmp(&score1));
-        vec.sort_by_key(|&(score, _, _)| score);
-        vec.into_iter().rev()
+/// One line of the persistent high scores table.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub name: String,
+    /// Set for Kitties, entries stored before co-op have none.
+    #[serde(default)]
+    pub player: Option<PlayerId>,
+    /// Asset path of the image shown next to the name.
+    pub avatar: String,
+    pub score: u32,
+    pub date: DateTime<Utc>,
+    pub duration: Duration,
+}
+
+/// High scores which survive between sessions, sorted by score.
+#[derive(Resource)]
+pub struct HighScoreStore {
+    backend: Box<dyn StorageBackend>,
+    entries: Vec<HighScoreEntry>,
+    limit: usize,
+}
+
+impl Default for HighScoreStore {
+    fn default() -> Self {
+        HighScoreStore::load(
+            default_backend(HIGHSCORES_STORAGE_NAME),
+            HIGHSCORES_LIMIT,
+        )
+    }
+}
+
+impl HighScoreStore {
+    /// Reads stored entries from `backend`, starts with an empty table if
+    /// there is nothing to read or stored data is broken.
+    pub fn load(backend: Box<dyn StorageBackend>, limit: usize) -> Self {
+        let entries = match backend.read().and_then(|data| match data {
+            Some(data) => deserialize_entries(&data),
+            None => Ok(Vec::new()),
+        }) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!("Can't load high scores, starting fresh: {}", e);
+                Vec::new()
+            }
+        };
+        let mut store = HighScoreStore {
+            backend,
+            entries: Vec::new(),
+            limit,
+        };
+        store.merge(entries);
+        store
+    }
+
+    pub fn entries(&self) -> &[HighScoreEntry] {
+        &self.entries
+    }
+
+    /// Adds finished run entries, keeping only top `limit` of them.
+    pub fn merge(&mut self, run: impl IntoIterator<Item = HighScoreEntry>) {
+        self.entries.extend(run);
+        // Stable sort, so older entries stay above new ones with same score
+        self.entries
+            .sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        self.entries.truncate(self.limit);
+    }
+
+    pub fn save(&self) -> Result<(), StorageError> {
+        self.backend.write(&serialize_entries(&self.entries)?)
+    }
+}
+
+/// Time spent in the current run, excluding pauses.
+#[derive(Resource, Default)]
+pub struct RunTime(pub Stopwatch);
+
+/// Game pacing loaded from `PROGRESSION_PATH`.
+#[derive(Resource)]
+pub struct Progression {
+    pub handle: Handle<ProgressionData>,
+    fallback: ProgressionData,
+}
+
+impl FromWorld for Progression {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        Progression {
+            handle: asset_server.load(PROGRESSION_PATH),
+            fallback: ProgressionData::default(),
+        }
+    }
+}
+
+impl Progression {
+    /// Returns loaded pacing, or the built-in one while it is loading.
+    pub fn data<'a>(
+        &'a self,
+        assets: &'a Assets<ProgressionData>,
+    ) -> &'a ProgressionData {
+        assets.get(&self.handle).unwrap_or(&self.fallback)
     }
 }
 
@@ -105,3 +246,77 @@ impl Chart {
         self.lines.get(pos)
     }
 }
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::game::fish::components::FishKind;
+    use crate::storage::MemoryBackend;
+
+    fn entry(name: &str, score: u32) -> HighScoreEntry {
+        HighScoreEntry {
+            name: name.to_string(),
+            player: None,
+            avatar: String::from("sprites/Cat's face blue.png"),
+            score,
+            date: Utc::now(),
+            duration: Duration::from_secs(90),
+        }
+    }
+
+    #[test]
+    fn rotten_fish_never_drop_score_below_zero() {
+        let mut score = Score::default();
+        let kitty = Entity::from_raw(0);
+        assert_eq!(score.add_score_to(&kitty, 5), 5);
+        assert_eq!(score.add_score_to(&kitty, -3), 2);
+        assert_eq!(score.add_score_to(&kitty, -3), 0);
+    }
+
+    #[test]
+    fn dog_hit_after_rotten_fish_drops_nothing() {
+        let mut score = Score::default();
+        let kitty = Entity::from_raw(0);
+        score.add_score_to(&kitty, 7);
+        score.add_score_to(&kitty, FishKind::Rotten.value());
+        assert_eq!(score.drop_on_hit(kitty), 0);
+        assert_eq!(score.get_score(&kitty).unwrap(), 4);
+    }
+
+    #[test]
+    fn merge_keeps_top_entries_sorted() {
+        let mut store =
+            HighScoreStore::load(Box::new(MemoryBackend::default()), 3);
+        store.merge([entry("Kitty", 5), entry("Bob", 12)]);
+        store.merge([entry("Kitty", 7), entry("Toby", 1)]);
+
+        let scores: Vec<_> = store.entries().iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![12, 7, 5]);
+    }
+
+    #[test]
+    fn saved_entries_are_loaded_back() {
+        let backend = MemoryBackend::default();
+        let mut store = HighScoreStore::load(Box::new(backend.clone()), 10);
+        store.merge([entry("Kitty", 42)]);
+        store.save().unwrap();
+
+        let store = HighScoreStore::load(Box::new(backend), 10);
+        assert_eq!(store.entries().len(), 1);
+        assert_eq!(store.entries()[0].name, "Kitty");
+        assert_eq!(store.entries()[0].score, 42);
+    }
+
+    #[test]
+    fn unknown_version_starts_fresh() {
+        let backend = MemoryBackend(Arc::new(Mutex::new(Some(String::from(
+            r#"{"version": 999, "entries": []}"#,
+        )))));
+        let store = HighScoreStore::load(Box::new(backend), 10);
+        assert!(store.entries().is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::resources::HighScoreEntry;
+use crate::storage::{check_version, StorageError};
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+/// Version of the on-disk high-scores format. Bump it when
+/// `HighScoresFile` layout changes in an incompatible way.
+pub const HIGHSCORES_FORMAT_VERSION: u32 = 1;
+
+/// Name of the high scores file (or `localStorage` key on the web).
+pub const HIGHSCORES_STORAGE_NAME: &str = "highscores";
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+#[derive(Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    entries: Vec<HighScoreEntry>,
+}
+
+pub fn serialize_entries(
+    entries: &[HighScoreEntry],
+) -> Result<String, StorageError> {
+    let file = HighScoresFile {
+        version: HIGHSCORES_FORMAT_VERSION,
+        entries: entries.to_vec(),
+    };
+    serde_json::to_string_pretty(&file)
+        .map_err(|e| StorageError::Format(e.to_string()))
+}
+
+pub fn deserialize_entries(
+    data: &str,
+) -> Result<Vec<HighScoreEntry>, StorageError> {
+    check_version(data, HIGHSCORES_FORMAT_VERSION)?;
+
+    serde_json::from_str::<HighScoresFile>(data)
+        .map(|file| file.entries)
+        .map_err(|e| StorageError::Format(e.to_string()))
+}
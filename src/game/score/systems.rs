@@ -1,23 +1,77 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use chrono::Utc;
 use rand::Rng;
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use super::{
-    resources::{Chart, HighScores, Score, ScoreLine},
+    assets::ProgressionData,
+    resources::{
+        Chart, HighScoreEntry, HighScoreStore, HighScores, Progression,
+        RunTime, Score, ScoreLine, ScoreOwner,
+    },
     ScoreUpdateEvent,
 };
 use crate::game::{
-    enemy::{components::Enemy, resources::DogResource},
+    enemy::{
+        components::{DogStats, Enemy},
+        resources::DogResource,
+        DogSpawnRequest,
+    },
     fish::components::FishWasPickedEvent,
+    fish_boss::{resources::BossMilestones, BossSpawnRequest},
     gui::components::Avatar,
-    player::components::Player,
+    player::components::{Player, PlayerId},
 };
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 pub fn insert_score(mut commands: Commands) {
     commands.insert_resource(Score::default());
+    commands.insert_resource(RunTime::default());
+}
+
+/// Kitty starts with no crackers, so it has a score before it picks anything.
+pub fn insert_player_scores(
+    player_query: Query<Entity, Added<Player>>,
+    mut score: ResMut<Score>,
+) {
+    for entity in player_query.iter() {
+        score.data.entry(entity).or_insert(0);
+    }
+}
+
+pub fn tick_run_time(mut run_time: ResMut<RunTime>, time: Res<Time>) {
+    run_time.0.tick(time.delta());
+}
+
+/// Merges results of the finished run into the persistent high scores.
+pub fn store_run_highscores(
+    highscores: Res<HighScores>,
+    run_time: Res<RunTime>,
+    asset_server: Res<AssetServer>,
+    mut store: ResMut<HighScoreStore>,
+) {
+    let date = Utc::now();
+    let duration = run_time.0.elapsed();
+
+    store.merge(highscores.scores.iter().filter_map(
+        |(owner, (image, score))| {
+            let avatar = asset_server.get_handle_path(image)?;
+            Some(HighScoreEntry {
+                name: owner.name(),
+                player: owner.player(),
+                avatar: avatar.path().to_string_lossy().into_owned(),
+                score: *score,
+                date,
+                duration,
+            })
+        },
+    ));
+
+    if let Err(e) = store.save() {
+        warn!("Can't save high scores: {}", e);
+    }
 }
 
 pub fn remove_score(mut commands: Commands) {
@@ -64,13 +118,15 @@ pub fn update_chart_data(
 
 pub fn update_highscores(
     entities_query_sprites: Query<
-        (Entity, &Handle<Image>, &Name),
+        (Entity, &Handle<Image>, &Name, Option<&PlayerId>),
         Or<(With<Player>, With<Enemy>)>,
     >,
-    entities_query_atlases: Query<
-        (Entity, &Handle<TextureAtlas>, &Name),
-        With<Enemy>,
-    >,
+    entities_query_atlases: Query<(
+        Entity,
+        &Handle<TextureAtlas>,
+        &Name,
+        &Enemy,
+    )>,
     scores: Option<Res<Score>>,
     mut highscores: ResMut<HighScores>,
     dogs_resource: Option<Res<DogResource>>,
@@ -79,29 +135,24 @@ pub fn update_highscores(
         if scores.is_changed() {
             for (&entity, &score) in scores.data.iter() {
                 let iterator = entities_query_sprites.iter().chain(
-                    entities_query_atlases.iter().map(|(e, _, name)| {
-                        (
-                            e,
-                            &dogs_resource
-                                .as_ref()
-                                .unwrap()
-                                .dogs
-                                .iter()
-                                .find(|&dog| {
-                                    dog.texture_identifier == "FaceBigBoy"
-                                })
-                                .unwrap()
-                                .texture,
-                            name,
-                        )
-                    }),
+                    // Animated dogs are shown with their face sprite
+                    entities_query_atlases.iter().filter_map(
+                        |(e, _, name, enemy)| {
+                            let dog = dogs_resource
+                                .as_ref()?
+                                .get(&enemy.roster_id)?;
+                            Some((e, &dog.texture, name, None))
+                        },
+                    ),
                 );
 
-                for (e, image, name) in iterator {
+                for (e, image, name, player) in iterator {
                     if e == entity {
-                        highscores
-                            .scores
-                            .insert(name.clone(), (image.clone(), score));
+                        let owner = match player {
+                            Some(id) => ScoreOwner::Player(*id),
+                            None => ScoreOwner::Dog(name.clone()),
+                        };
+                        highscores.scores.insert(owner, (image.clone(), score));
                     }
                 }
             }
@@ -109,30 +160,88 @@ pub fn update_highscores(
     }
 }
 
+/// Events which scores reaching milestones send.
+#[derive(SystemParam)]
+pub struct MilestoneEvents<'w> {
+    score_update: EventWriter<'w, ScoreUpdateEvent>,
+    dog_spawn_request: EventWriter<'w, DogSpawnRequest>,
+    boss_spawn_request: EventWriter<'w, BossSpawnRequest>,
+}
+
 pub fn update_score(
     mut picked_event: EventReader<FishWasPickedEvent>,
-    mut score_update_event: EventWriter<ScoreUpdateEvent>,
-    entity_query: Query<(&Name, Entity), Or<(With<Player>, With<Enemy>)>>,
+    mut milestone_events: MilestoneEvents,
+    entity_query: Query<(&Name, Option<&Player>, Option<&DogStats>)>,
+    progression: Res<Progression>,
+    progression_assets: Res<Assets<ProgressionData>>,
+    mut boss_milestones: ResMut<BossMilestones>,
     mut score: ResMut<Score>,
 ) {
+    let progression = progression.data(&progression_assets);
     for event in picked_event.iter() {
-        for (name, entity) in entity_query.iter() {
-            if entity == event.0 {
-                let loot_count = if name.to_string() == "Kitty" {
-                    1
-                } else {
-                    rand::thread_rng().gen_range(1..10)
-                };
-
-                let new_score = score.add_score_to(&entity, loot_count);
-
-                if new_score % 7 == 0 || new_score == 1 {
-                    score_update_event.send(ScoreUpdateEvent::new(
+        if let Ok((name, player, stats)) = entity_query.get(event.picker) {
+            // Dogs carry off several crackers per one the fish is worth
+            let loot_count = match stats {
+                Some(stats) if player.is_none() => {
+                    rand::thread_rng().gen_range(stats.loot.clone())
+                }
+                _ => 1,
+            };
+
+            let old_score = score.get_score(&event.picker).unwrap_or(0);
+            let new_score = score.add_score_to(
+                &event.picker,
+                loot_count as i32 * event.kind.value(),
+            );
+
+            // Valuable fish may jump over several milestones at once
+            for reached in old_score + 1..=new_score {
+                if progression.fires_message(reached) {
+                    milestone_events.score_update.send(ScoreUpdateEvent::new(
                         name.clone(),
-                        super::ScoreEventType::ReachedMilestone(new_score),
+                        super::ScoreEventType::ReachedMilestone(reached),
                     ))
                 }
+
+                // Only player's progress brings new dogs and the boss
+                if player.is_some() {
+                    if let Some(dog_types) = progression.spawns_dog(reached) {
+                        milestone_events.dog_spawn_request.send(
+                            DogSpawnRequest {
+                                dog_types: dog_types.to_vec(),
+                            },
+                        );
+                    }
+                    if boss_milestones.reach(reached, progression) {
+                        milestone_events
+                            .boss_spawn_request
+                            .send(BossSpawnRequest);
+                    }
+                }
             }
         }
     }
 }
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_kitty_is_hit_before_picking_anything() {
+        let mut app = App::new();
+        app.init_resource::<Score>()
+            .add_system(insert_player_scores);
+        let first = app.world.spawn(Player::new(3)).id();
+        let second = app.world.spawn(Player::new(3)).id();
+        app.update();
+
+        let mut score = app.world.resource_mut::<Score>();
+        score.add_score_to(&first, 20);
+        assert_eq!(score.get_score(&second).unwrap(), 0);
+        assert_eq!(score.drop_on_hit(second), 0);
+        assert_eq!(score.drop_on_hit(first), 2);
+    }
+}
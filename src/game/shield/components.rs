@@ -2,7 +2,12 @@ use bevy::prelude::*;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-#[derive(Resource, Default)]
-pub struct FlyingMilkResource {
-    pub timer: Option<Timer>,
+/// Kitty carries a shield which absorbs the next dog hit.
+#[derive(Component)]
+pub struct Shield;
+
+/// Bubble sprite around the Kitty while it's shielded.
+#[derive(Component)]
+pub struct ShieldBubble {
+    pub elapsed: f32,
 }
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::pickups::{
+    components::PickupKind,
+    resources::{PickupSpec, Trajectory},
+    RegisterPickup,
+};
+use crate::AppState;
+
+use self::systems::{
+    animate_shield_bubble, break_shield, put_shield_on, spawn_shield_bubble,
+};
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+pub mod components;
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+const SHIELD_SPEED: f32 = 220.;
+const MIN_SPAWN_TIME: f32 = 20.;
+const MAX_SPAWN_TIME: f32 = 60.;
+const BUBBLE_SIZE: f32 = 150.;
+/// Short invulnerability after the shield absorbed a hit, so the same dog
+/// can't hurt Kitty right away.
+pub const SHIELD_BREAK_INVULNERABILITY: f32 = 1.;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub struct ShieldPlugin;
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Pickups
+            .register_pickup(
+                PickupKind::Shield,
+                PickupSpec {
+                    wanted_by: |target| !target.shielded,
+                    spawn_delay: (MIN_SPAWN_TIME, MAX_SPAWN_TIME),
+                    trajectory: Trajectory {
+                        speed: SHIELD_SPEED,
+                        spin: 0.5,
+                        wild_spin: 0.,
+                        wild_spin_chance: 0.,
+                    },
+                    texture: "sprites/Shield.png",
+                    size: Vec2::new(128., 148.) / 3.,
+                    collider_radius: 64. / 3.,
+                    sound: |sample_pack| &sample_pack.button,
+                    volume: 0.7,
+                    picked_message: Some("Shields up!"),
+                    escaped_message: None,
+                },
+            )
+            // Events
+            .add_event::<ShieldBrokenEvent>()
+            // Systems
+            .add_systems(
+                (
+                    put_shield_on,
+                    spawn_shield_bubble,
+                    break_shield,
+                    animate_shield_bubble,
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            );
+    }
+}
+
+// Events
+
+/// Shield absorbed a dog hit instead of the Kitty.
+pub struct ShieldBrokenEvent {
+    pub player: Entity,
+}
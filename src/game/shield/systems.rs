@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use kira::sound::static_sound::StaticSoundSettings;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::{Shield, ShieldBubble};
+use super::{ShieldBrokenEvent, BUBBLE_SIZE};
+use crate::audio::assets::AudioSource;
+use crate::audio::resources::{KiraManager, SamplePack};
+use crate::game::pickups::{components::PickupKind, PickupCollectedEvent};
+use crate::game::player::components::Player;
+use crate::game::player::PlayerState;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn put_shield_on(
+    mut commands: Commands,
+    mut collected_events: EventReader<PickupCollectedEvent>,
+    mut player_query: Query<(Option<&Shield>, &mut PlayerState), With<Player>>,
+) {
+    for event in collected_events.iter() {
+        if event.kind != PickupKind::Shield {
+            continue;
+        }
+        let Ok((shield, mut state)) = player_query.get_mut(event.player) else {
+            continue;
+        };
+        if shield.is_some() {
+            continue;
+        }
+
+        commands.entity(event.player).insert(Shield);
+        // Invulnerable Kitty becomes shielded when invulnerability ends
+        if *state == PlayerState::Vulnerable {
+            *state = PlayerState::Shielded;
+        }
+    }
+}
+
+pub fn spawn_shield_bubble(
+    mut commands: Commands,
+    player_query: Query<Entity, Added<Shield>>,
+    asset_server: Res<AssetServer>,
+) {
+    for player in player_query.iter() {
+        commands.entity(player).with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        custom_size: Some(Vec2::splat(BUBBLE_SIZE)),
+                        ..default()
+                    },
+                    texture: asset_server.load("sprites/Shield bubble.png"),
+                    transform: Transform::from_xyz(0., 0., 0.5),
+                    ..default()
+                },
+                ShieldBubble { elapsed: 0. },
+            ));
+        });
+    }
+}
+
+pub fn break_shield(
+    mut commands: Commands,
+    mut shield_events: EventReader<ShieldBrokenEvent>,
+    player_query: Query<(), (With<Player>, With<Shield>)>,
+    bubble_query: Query<(Entity, &Parent), With<ShieldBubble>>,
+    mut kira_manager: NonSendMut<KiraManager>,
+    audio_assets: Res<Assets<AudioSource>>,
+    sample_pack: Res<SamplePack>,
+) {
+    for event in shield_events.iter() {
+        if player_query.contains(event.player) {
+            commands.entity(event.player).remove::<Shield>();
+        }
+        for (bubble, parent) in bubble_query.iter() {
+            if parent.get() == event.player {
+                commands.entity(bubble).despawn_recursive();
+            }
+        }
+
+        // Play shield breaking sound
+        let sound_data = audio_assets
+            .get(&sample_pack.wall_collision)
+            .unwrap()
+            .get()
+            .with_settings(
+                StaticSoundSettings::new()
+                    .volume(0.6)
+                    .output_destination(kira_manager.get_master()),
+            );
+        kira_manager.play(sound_data).unwrap();
+    }
+}
+
+pub fn animate_shield_bubble(
+    mut bubble_query: Query<(&mut Transform, &mut ShieldBubble)>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut bubble) in bubble_query.iter_mut() {
+        bubble.elapsed += time.delta_seconds();
+        transform.scale = Vec3::splat(1. + (bubble.elapsed * 4.).sin() * 0.04);
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::effects::components::{EffectKind, Modifiers};
+use super::pickups::{
+    components::PickupKind,
+    resources::{PickupSpec, Trajectory},
+    RegisterPickup,
+};
+use super::SimulationState;
+use crate::AppState;
+
+use self::systems::boost_player;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+const SPEED_BOOST_SPEED: f32 = 240.;
+const MIN_SPAWN_TIME: f32 = 15.;
+const MAX_SPAWN_TIME: f32 = 45.;
+const SPEED_BOOST_DURATION: f32 = 8.;
+/// Faster engine and less damping make Kitty really fast, bigger pickup
+/// area helps to catch fish on such speed.
+const SPEED_BOOST_MODIFIERS: Modifiers = Modifiers {
+    thrust: 1.6,
+    damping: 0.7,
+    pickup_radius: 1.8,
+};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub struct SpeedBoostPlugin;
+
+impl Plugin for SpeedBoostPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Pickups
+            .register_pickup(
+                PickupKind::SpeedBoost,
+                PickupSpec {
+                    // Next boost starts flying only when some Kitty is out
+                    // of the previous one
+                    wanted_by: |target| {
+                        !target.effects.is_active(EffectKind::SpeedBoost)
+                    },
+                    spawn_delay: (MIN_SPAWN_TIME, MAX_SPAWN_TIME),
+                    trajectory: Trajectory {
+                        speed: SPEED_BOOST_SPEED,
+                        spin: 1.,
+                        wild_spin: 0.,
+                        wild_spin_chance: 0.,
+                    },
+                    texture: "sprites/Speed boost.png",
+                    size: Vec2::new(128., 160.) / 3.5,
+                    collider_radius: 64. / 3.5,
+                    sound: |sample_pack| &sample_pack.button,
+                    volume: 0.7,
+                    picked_message: Some("Full throttle!"),
+                    escaped_message: None,
+                },
+            )
+            // Systems
+            .add_system(
+                boost_player
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            );
+    }
+}
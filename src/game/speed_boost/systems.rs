@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::{SPEED_BOOST_DURATION, SPEED_BOOST_MODIFIERS};
+use crate::game::effects::components::{
+    EffectKind, StatusEffect, StatusEffects,
+};
+use crate::game::pickups::{components::PickupKind, PickupCollectedEvent};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn boost_player(
+    mut collected_events: EventReader<PickupCollectedEvent>,
+    mut effects_query: Query<&mut StatusEffects>,
+) {
+    for event in collected_events.iter() {
+        if event.kind != PickupKind::SpeedBoost {
+            continue;
+        }
+        let Ok(mut effects) = effects_query.get_mut(event.player) else {
+            continue;
+        };
+        effects.add(StatusEffect::new(
+            EffectKind::SpeedBoost,
+            SPEED_BOOST_MODIFIERS,
+            SPEED_BOOST_DURATION,
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::{resources::RunStats, systems::*};
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+pub mod resources;
+pub mod systems;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Collects statistics of the current run for the game over screen.
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<RunStats>()
+            // Enter State Systems
+            .add_system(reset_run_stats.in_schedule(OnEnter(AppState::Game)))
+            // Systems
+            .add_system(collect_run_stats.in_set(OnUpdate(AppState::Game)));
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// What happened during the current run. Lives until the next run starts, so
+/// game over screen can show it.
+#[derive(Resource, Default, Debug)]
+pub struct RunStats {
+    /// Fish picked by the player.
+    pub fish_picked: u32,
+    /// Fish picked by dogs.
+    pub fish_lost: u32,
+    pub crackers_dropped: u32,
+    pub hits_taken: u32,
+    pub milk_drunk: u32,
+    pub milk_escaped: u32,
+    pub dogs_met: u32,
+}
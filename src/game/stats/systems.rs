@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::resources::RunStats;
+use crate::events::PlayerHit;
+use crate::game::{
+    enemy::EnemyIsArrivingEvent,
+    fish::components::FishWasPickedEvent,
+    pickups::{components::PickupKind, PickupEscapedEvent},
+    player::components::Player,
+    regeneration::RegeneratePlayerEvent,
+};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn reset_run_stats(mut commands: Commands) {
+    commands.insert_resource(RunStats::default());
+}
+
+pub fn collect_run_stats(
+    mut stats: ResMut<RunStats>,
+    player_query: Query<Entity, With<Player>>,
+    mut picked_events: EventReader<FishWasPickedEvent>,
+    mut hit_events: EventReader<PlayerHit>,
+    mut regen_events: EventReader<RegeneratePlayerEvent>,
+    mut escaped_events: EventReader<PickupEscapedEvent>,
+    mut arriving_events: EventReader<EnemyIsArrivingEvent>,
+) {
+    for event in picked_events.iter() {
+        if player_query.contains(event.picker) {
+            stats.fish_picked += 1;
+        } else {
+            stats.fish_lost += 1;
+        }
+    }
+    for event in hit_events.iter() {
+        stats.hits_taken += 1;
+        stats.crackers_dropped += event.drop_count;
+    }
+    stats.milk_drunk += regen_events.iter().count() as u32;
+    stats.milk_escaped += escaped_events
+        .iter()
+        .filter(|event| event.kind == PickupKind::Milk)
+        .count() as u32;
+    stats.dogs_met += arriving_events.iter().count() as u32;
+}
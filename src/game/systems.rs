@@ -1,7 +1,11 @@
 use std::time::Duration;
 
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
 use bevy_rapier2d::prelude::*;
+use bevy_tweening::Animator;
 use kira::{clock::ClockSpeed, sound::static_sound::StaticSoundSettings};
 
 // ───── Current Crate Imports ────────────────────────────────────────────── //
@@ -11,15 +15,17 @@ use crate::{
         assets::AudioSource,
         resources::{KiraManager, SamplePack, SoundHandleResource},
     },
-    helper_functions::get_camera_borders,
+    controls::resources::{Action, ActionState},
     AppState,
 };
 
 use super::{
     components::{ControlsSheet, Wall},
-    enemy::DoggyTheme,
-    resources::GameData,
-    SimulationState,
+    enemy::{components::Enemy, DoggyTheme},
+    fish::{components::Fish, systems::get_fish_tween},
+    player::components::Player,
+    resources::{clamp_view_center, frame_targets, Arena, GameData},
+    SimulationState, CAMERA_MARGIN, CAMERA_STIFFNESS, RESIZE_MARGIN,
 };
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
@@ -38,10 +44,18 @@ pub fn resume_simulation(
 
 pub fn toggle_simulation_on_input_event(
     mut commands: Commands,
-    keyboard_input: Res<Input<KeyCode>>,
+    action_state: Res<ActionState>,
     simulation_state: Res<State<SimulationState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Space) {
+    // Back only pauses, so a stray press never ends the run
+    if action_state.just_pressed(Action::Back)
+        && simulation_state.0 == SimulationState::Running
+    {
+        commands.insert_resource(NextState(Some(SimulationState::Paused)));
+        return;
+    }
+
+    if action_state.just_pressed(Action::Pause) {
         if simulation_state.0 == SimulationState::Running {
             commands.insert_resource(NextState(Some(SimulationState::Paused)));
             println!("Simulation paused");
@@ -57,69 +71,116 @@ pub fn toggle_simulation_on_input_event(
 pub fn spawn_world_borders(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    arena: Res<Arena>,
 ) {
-    let window = window_query.single();
+    let rect = arena.rect(window_query.single());
 
-    let (cam_transform, cam_projection) = camera_query.single();
+    for wall in [Wall::Top, Wall::Bottom, Wall::Left, Wall::Right] {
+        let (translation, collider) = wall_placement(wall, rect);
+        commands.spawn((
+            SpatialBundle {
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+            collider,
+            wall,
+        ));
+    }
+}
 
-    let (left, right, top, bottom) =
-        get_camera_borders(cam_transform, cam_projection.area);
+/// Moves walls to the edges of the arena, which has been stretched or
+/// shrunk together with the window.
+pub fn rebuild_world_borders_on_resize(
+    mut resize_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut walls_query: Query<(&Wall, &mut Transform, &mut Collider)>,
+    arena: Res<Arena>,
+) {
+    let Some(window) = resize_events
+        .iter()
+        .filter_map(|event| window_query.get(event.window).ok())
+        .last()
+    else {
+        return;
+    };
+    let rect = arena.rect(window);
 
-    let horizontal = Collider::cuboid(window.width(), 2.);
-    let vertical = Collider::cuboid(2., window.height());
+    for (wall, mut transform, mut collider) in walls_query.iter_mut() {
+        let (translation, shape) = wall_placement(*wall, rect);
+        transform.translation = translation;
+        *collider = shape;
+    }
+}
 
-    // Top border
-    commands.spawn((
-        SpatialBundle {
-            transform: Transform {
-                translation: top,
-                ..default()
-            },
-            ..default()
-        },
-        horizontal.clone(),
-        Wall::Top,
-    ));
+type ArenaDwellersQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        Option<&'static mut Animator<Transform>>,
+        Option<&'static Enemy>,
+    ),
+    Or<(With<Player>, With<Fish>, With<Enemy>)>,
+>;
 
-    // Bottom border
-    commands.spawn((
-        SpatialBundle {
-            transform: Transform {
-                translation: bottom,
-                ..default()
-            },
-            ..default()
-        },
-        horizontal,
-        Wall::Bottom,
-    ));
+/// Brings back Kitties, fish and dogs which are left behind the walls
+/// after the arena has shrunk.
+pub fn keep_entities_inside_arena(
+    mut resize_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut entities_query: ArenaDwellersQuery,
+    arena: Res<Arena>,
+) {
+    let Some(window) = resize_events
+        .iter()
+        .filter_map(|event| window_query.get(event.window).ok())
+        .last()
+    else {
+        return;
+    };
+    let rect = arena.rect(window);
+    let min = rect.min + Vec2::splat(RESIZE_MARGIN);
+    let max = rect.max - Vec2::splat(RESIZE_MARGIN);
 
-    // Left border
-    commands.spawn((
-        SpatialBundle {
-            transform: Transform {
-                translation: left,
-                ..default()
-            },
-            ..default()
-        },
-        vertical.clone(),
-        Wall::Left,
-    ));
+    for (mut transform, animator, enemy) in entities_query.iter_mut() {
+        // Dogs without collider are still flying into the arena
+        if enemy.is_some_and(|enemy| !enemy.has_collider) {
+            continue;
+        }
+        let position = transform.translation.truncate();
+        let inside = position.clamp(min, max);
+        if inside == position {
+            continue;
+        }
+        transform.translation = inside.extend(transform.translation.z);
+        // Wobbling fish would be pulled back by its tween otherwise
+        if let Some(mut animator) = animator {
+            animator.set_tweenable(get_fish_tween(transform.translation));
+        }
+    }
+}
 
-    // Right border
-    commands.spawn((
-        SpatialBundle {
-            transform: Transform {
-                translation: right,
-                ..default()
-            },
-            ..default()
-        },
-        vertical,
-        Wall::Right,
-    ));
+/// Position and collider of `wall` along the `arena` edge.
+fn wall_placement(wall: Wall, arena: Rect) -> (Vec3, Collider) {
+    let center = arena.center();
+    match wall {
+        Wall::Top => (
+            Vec3::new(center.x, arena.max.y, 0.),
+            Collider::cuboid(arena.width(), 2.),
+        ),
+        Wall::Bottom => (
+            Vec3::new(center.x, arena.min.y, 0.),
+            Collider::cuboid(arena.width(), 2.),
+        ),
+        Wall::Left => (
+            Vec3::new(arena.min.x, center.y, 0.),
+            Collider::cuboid(2., arena.height()),
+        ),
+        Wall::Right => (
+            Vec3::new(arena.max.x, center.y, 0.),
+            Collider::cuboid(2., arena.height()),
+        ),
+    }
 }
 
 pub fn despawn_borders(
@@ -131,10 +192,76 @@ pub fn despawn_borders(
     }
 }
 
+/// Kitty starts in the arena center, so the camera does too.
+pub fn center_camera_on_arena(
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+) {
+    let center = arena.rect(window_query.single()).center();
+    let mut transform = camera_query.single_mut();
+    transform.translation = center.extend(transform.translation.z);
+}
+
+/// Smoothly moves the camera to Kitties, but never past the arena edges.
+/// In co-op camera zooms out to keep both Kitties in view.
+pub fn follow_players_with_camera(
+    mut camera_query: Query<
+        (&mut Transform, &mut OrthographicProjection),
+        With<Camera2d>,
+    >,
+    player_query: Query<&Transform, (With<Player>, Without<Camera2d>)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+) {
+    let (mut transform, mut projection) = camera_query.single_mut();
+    let arena = arena.rect(window_query.single());
+    // Projection area is already zoomed
+    let half_view = projection.area.half_size() / projection.scale;
+
+    let players: Vec<_> = player_query
+        .iter()
+        .map(|player| player.translation.truncate())
+        .collect();
+    let Some((target, target_zoom)) =
+        frame_targets(&players, half_view, CAMERA_MARGIN, arena)
+    else {
+        return;
+    };
+
+    let step = 1. - (-CAMERA_STIFFNESS * time.delta_seconds()).exp();
+    let zoom = projection.scale + (target_zoom - projection.scale) * step;
+    if (zoom - projection.scale).abs() > f32::EPSILON {
+        projection.scale = zoom;
+    }
+
+    let position = transform.translation.truncate();
+    let next =
+        clamp_view_center(position.lerp(target, step), half_view * zoom, arena);
+    transform.translation = next.extend(transform.translation.z);
+}
+
+/// Menus are laid out for the camera looking at the window center.
+pub fn reset_camera(
+    mut camera_query: Query<
+        (&mut Transform, &mut OrthographicProjection),
+        With<Camera2d>,
+    >,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let window = window_query.single();
+    let (mut transform, mut projection) = camera_query.single_mut();
+    projection.scale = 1.;
+    transform.translation.x = window.width() / 2.;
+    transform.translation.y = window.height() / 2.;
+}
+
 pub fn spawn_controls_sheet(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     camera_query: Query<&Transform, With<Camera2d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
     game_res: Res<GameData>,
 ) {
     if game_res.sheet_was_shown {
@@ -142,7 +269,7 @@ pub fn spawn_controls_sheet(
     }
 
     let mut position = camera_query.single().translation;
-    position.y -= camera_query.single().translation.y / 2.;
+    position.y -= window_query.single().height() / 4.;
     commands.spawn((
         SpriteBundle {
             transform: Transform::from_translation(position),
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::{FIRE_COOLDOWN, MAX_AMMO, RELOAD_TIME};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Kitty's water gun. Every shot takes one unit of water, the tank is
+/// refilled one unit per `RELOAD_TIME`.
+#[derive(Component)]
+pub struct WaterGun {
+    pub ammo: u32,
+    pub max_ammo: u32,
+    pub cooldown: Timer,
+    pub reload: Timer,
+}
+
+impl Default for WaterGun {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(FIRE_COOLDOWN, TimerMode::Once);
+        // Ready to fire right away
+        cooldown.tick(Duration::from_secs_f32(FIRE_COOLDOWN));
+        WaterGun {
+            ammo: MAX_AMMO,
+            max_ammo: MAX_AMMO,
+            cooldown,
+            reload: Timer::from_seconds(RELOAD_TIME, TimerMode::Repeating),
+        }
+    }
+}
+
+impl WaterGun {
+    pub fn tick(&mut self, delta: Duration) {
+        self.cooldown.tick(delta);
+        if self.ammo < self.max_ammo {
+            if self.reload.tick(delta).just_finished() {
+                self.ammo += 1;
+            }
+        } else {
+            self.reload.reset();
+        }
+    }
+
+    /// Takes one shot of water if the gun is ready.
+    pub fn try_fire(&mut self) -> bool {
+        if self.ammo == 0 || !self.cooldown.finished() {
+            return false;
+        }
+        self.ammo -= 1;
+        self.cooldown.reset();
+        true
+    }
+
+    /// How full the tank is, including the unit being refilled, in `0..=1`.
+    pub fn fill(&self) -> f32 {
+        let refilling = if self.ammo < self.max_ammo {
+            self.reload.percent()
+        } else {
+            0.
+        };
+        (self.ammo as f32 + refilling) / self.max_ammo as f32
+    }
+}
+
+#[derive(Component)]
+pub struct WaterDrop {
+    pub direction: Vec2,
+    pub covered_distance: f32,
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gun_respects_cooldown_and_reloads() {
+        let mut gun = WaterGun::default();
+        assert!(gun.try_fire());
+        // Cooldown is not over yet
+        assert!(!gun.try_fire());
+        assert_eq!(gun.ammo, MAX_AMMO - 1);
+
+        gun.tick(Duration::from_secs_f32(RELOAD_TIME));
+        assert_eq!(gun.ammo, MAX_AMMO);
+        assert_eq!(gun.fill(), 1.);
+    }
+
+    #[test]
+    fn empty_gun_does_not_fire() {
+        let mut gun = WaterGun::default();
+        for _ in 0..MAX_AMMO {
+            assert!(gun.try_fire());
+            gun.cooldown.tick(Duration::from_secs_f32(FIRE_COOLDOWN));
+        }
+        assert!(!gun.try_fire());
+        assert_eq!(gun.fill(), 0.);
+    }
+}
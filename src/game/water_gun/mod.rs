@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::systems::*;
+use super::SimulationState;
+use crate::AppState;
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+// Top-level modules
+pub mod components;
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+const MAX_AMMO: u32 = 8;
+const RELOAD_TIME: f32 = 1.5;
+const FIRE_COOLDOWN: f32 = 0.25;
+const WATER_DROP_SPEED: f32 = 700.;
+const WATER_DROP_RANGE: f32 = 650.;
+const WATER_DROP_SIZE: Vec2 = Vec2::new(16., 24.);
+/// Impulse of a single drop, light dogs fly away, heavy ones barely move.
+const WATER_IMPULSE: f32 = 150.;
+/// Time a splashed dog drifts with the impulse instead of running.
+const DRENCHED_TIME: f32 = 0.8;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub struct WaterGunPlugin;
+
+impl Plugin for WaterGunPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Events
+            .add_event::<DogSplashedEvent>()
+            // Systems
+            .add_systems(
+                (fire_water_gun, move_water_drops, splash_dogs)
+                    .chain()
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            )
+            // Exit State Systems
+            .add_system(
+                despawn_water_drops_on_exit_state
+                    .in_schedule(OnExit(AppState::Game)),
+            );
+    }
+}
+
+// Events
+
+/// Water drop hit the `dog`.
+pub struct DogSplashedEvent {
+    pub dog: Entity,
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use super::components::{WaterDrop, WaterGun};
+use super::{
+    DogSplashedEvent, DRENCHED_TIME, WATER_DROP_RANGE, WATER_DROP_SIZE,
+    WATER_DROP_SPEED, WATER_IMPULSE,
+};
+use crate::controls::resources::{Action, ActionState};
+use crate::game::enemy::components::{Drenched, Enemy};
+use crate::game::player::components::{Player, PlayerId, RocketEngineSprite};
+use crate::game::player::SPACESHIP_SIZE;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+pub fn fire_water_gun(
+    mut commands: Commands,
+    action_state: Res<ActionState>,
+    time: Res<Time>,
+    mut player_query: Query<
+        (Entity, &PlayerId, &GlobalTransform, &mut WaterGun),
+        With<Player>,
+    >,
+    engine_query: Query<(&Parent, &GlobalTransform), With<RocketEngineSprite>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (player, id, player_transform, mut water_gun) in player_query.iter_mut()
+    {
+        water_gun.tick(time.delta());
+
+        if !action_state.pressed(Action::Fire.of_player(*id))
+            || !water_gun.try_fire()
+        {
+            continue;
+        }
+
+        // Kitty faces the direction opposite to its rocket engine
+        let facing = engine_query
+            .iter()
+            .find(|(parent, _)| parent.get() == player)
+            .map_or(Vec2::Y, |(_, engine)| {
+                (engine.compute_transform().rotation * Vec3::Y)
+                    .truncate()
+                    .normalize_or_zero()
+            });
+        let position = player_transform.translation().truncate()
+            + facing * (SPACESHIP_SIZE / 2. + WATER_DROP_SIZE.y);
+
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(WATER_DROP_SIZE),
+                    ..default()
+                },
+                texture: asset_server.load("sprites/Water drop.png"),
+                transform: Transform::from_translation(position.extend(9.))
+                    .with_rotation(Quat::from_rotation_arc_2d(Vec2::Y, facing)),
+                ..default()
+            },
+            Sensor,
+            Collider::ball(WATER_DROP_SIZE.x / 2.),
+            WaterDrop {
+                direction: facing,
+                covered_distance: 0.,
+            },
+        ));
+    }
+}
+
+pub fn move_water_drops(
+    mut commands: Commands,
+    mut drop_query: Query<(Entity, &mut Transform, &mut WaterDrop)>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut drop) in drop_query.iter_mut() {
+        let step = drop.direction * WATER_DROP_SPEED * time.delta_seconds();
+        transform.translation += step.extend(0.);
+        drop.covered_distance += step.length();
+
+        if drop.covered_distance > WATER_DROP_RANGE {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Pushes dogs hit by water drops away along the drop direction.
+pub fn splash_dogs(
+    mut commands: Commands,
+    rapier_context: Res<RapierContext>,
+    drop_query: Query<(Entity, &WaterDrop)>,
+    enemy_query: Query<Entity, With<Enemy>>,
+    mut splash_events: EventWriter<DogSplashedEvent>,
+) {
+    for (drop_entity, drop) in drop_query.iter() {
+        let dog = rapier_context
+            .intersections_with(drop_entity)
+            .filter(|(_, _, intersecting)| *intersecting)
+            .map(|(e1, e2, _)| if e1 == drop_entity { e2 } else { e1 })
+            .find(|entity| enemy_query.contains(*entity));
+
+        if let Some(dog) = dog {
+            commands.entity(dog).insert((
+                ExternalImpulse {
+                    impulse: drop.direction * WATER_IMPULSE,
+                    torque_impulse: 0.,
+                },
+                Drenched(Timer::from_seconds(DRENCHED_TIME, TimerMode::Once)),
+            ));
+            commands.entity(drop_entity).despawn();
+            splash_events.send(DogSplashedEvent { dog });
+        }
+    }
+}
+
+pub fn despawn_water_drops_on_exit_state(
+    mut commands: Commands,
+    drop_query: Query<Entity, With<WaterDrop>>,
+) {
+    for entity in drop_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -6,6 +6,7 @@ use self::systems::layout::{
     despawn_gameover_layout, scroll_list, spawn_gameover_layout,
 };
 use self::systems::{play_gameover_theme, stop_gameover_theme};
+use crate::game::score::systems::store_run_highscores;
 use crate::systems::finalize_transition_to_game;
 use crate::{
     main_menu::systems::interactions::{
@@ -32,7 +33,10 @@ impl Plugin for GameoverPlugin {
         app
             // Enter State Systems
             .add_systems(
-                (spawn_gameover_layout, play_gameover_theme)
+                (
+                    spawn_gameover_layout.after(store_run_highscores),
+                    play_gameover_theme,
+                )
                     .in_schedule(OnEnter(AppState::GameOver)),
             )
             // Systems
@@ -105,11 +105,32 @@ pub const SCROLL_PARENT: Style = Style {
         top: Val::Px(10.),
         ..UiRect::DEFAULT
     },
-    max_size: Size::height(Val::Px(306.)),
+    max_size: Size::height(Val::Px(282.)),
     overflow: Overflow::Hidden,
     ..Style::DEFAULT
 };
 
+pub const STATS_PANEL: Style = Style {
+    flex_direction: FlexDirection::Row,
+    flex_wrap: FlexWrap::Wrap,
+    justify_content: JustifyContent::Center,
+    size: Size::width(Val::Percent(100.)),
+    margin: UiRect {
+        top: Val::Px(6.),
+        ..UiRect::DEFAULT
+    },
+    ..Style::DEFAULT
+};
+
+pub const STATS_ITEM: Style = Style {
+    margin: UiRect {
+        left: Val::Px(6.),
+        right: Val::Px(6.),
+        ..UiRect::DEFAULT
+    },
+    ..Style::DEFAULT
+};
+
 pub const SCROLL_VIEW: Style = Style {
     flex_direction: FlexDirection::Column,
     size: Size::width(Val::Px(439.9)),
@@ -133,6 +154,17 @@ pub const CAT_FACE: Style = Style {
     ..Style::DEFAULT
 };
 
+pub const NAME_COLUMN: Style = Style {
+    flex_direction: FlexDirection::Column,
+    margin: UiRect {
+        left: Val::Px(25.),
+        right: Val::Px(50.),
+        ..UiRect::DEFAULT
+    },
+    size: Size::width(Val::Px(100.)),
+    ..Style::DEFAULT
+};
+
 pub const BAG: Style = Style {
     size: Size::new(Val::Px(251. / 2.7), Val::Px(240. / 2.7)),
     justify_content: JustifyContent::Center,
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy::{
     a11y::{
         accesskit::{NodeBuilder, Role},
@@ -8,14 +10,19 @@ use bevy::{
 };
 
 use crate::{
-    game::score::resources::HighScores,
+    controls::components::Focusable,
+    game::{
+        player::components::PlayerId,
+        score::resources::{HighScoreEntry, HighScoreStore, RunTime},
+        stats::resources::RunStats,
+    },
     gameover::{
         components::{GameoverComponent, ScrollView},
         styles::{
             BAG, BOARD_FILL, BOARD_FRAME, CAT_FACE, DOG_FACE, EMITTING_FILL,
             EMITTING_FRAME, LEFT_BUTTON, LEFT_CONTAINER, MAIN_CONTAINER,
-            RIGHT_BUTTON, RIGHT_CONTAINER, ROW, SCROLL_PARENT, SCROLL_VIEW,
-            SPACESHIP,
+            NAME_COLUMN, RIGHT_BUTTON, RIGHT_CONTAINER, ROW, SCROLL_PARENT,
+            SCROLL_VIEW, SPACESHIP, STATS_ITEM, STATS_PANEL,
         },
     },
     main_menu::components::{PlayButton, QuitButton},
@@ -27,7 +34,9 @@ use crate::{
 pub fn spawn_gameover_layout(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    highscore: Res<HighScores>,
+    highscores: Res<HighScoreStore>,
+    run_stats: Res<RunStats>,
+    run_time: Res<RunTime>,
 ) {
     commands
         .spawn((NodeBundle {
@@ -58,6 +67,7 @@ pub fn spawn_gameover_layout(
                         hover_handle: asset_server.load("sprites/Gameover/Buttons/Quit hovered.png"),
                         click_handle: asset_server.load("sprites/Gameover/Buttons/Quit clicked.png"),
                     },
+                    Focusable { order: 0, color: Color::WHITE },
                     ));
                 }
             });
@@ -132,6 +142,9 @@ pub fn spawn_gameover_layout(
                                                             ..default()
                                                     });
 
+                                                    // Last run statistics
+                                                    spawn_stats_panel(parent, &run_stats, run_time.0.elapsed(), &asset_server);
+
                                                     // Scroll view
                                                     parent.
                                                         spawn(
@@ -149,8 +162,8 @@ pub fn spawn_gameover_layout(
                                                                     ScrollView { position: 0. },
                                                                     AccessibilityNode(NodeBuilder::new(Role::List)),
                                                                 )).with_children(|parent| {
-                                                                       for (idx, (score, name, image)) in highscore.sorted().enumerate() {
-                                                                            spawn_row(idx + 1, parent, name, image, score, &asset_server);
+                                                                       for (idx, entry) in highscores.entries().iter().enumerate() {
+                                                                            spawn_row(idx + 1, parent, entry, &asset_server);
                                                                        }             
                                                             
                                                             });
@@ -176,6 +189,7 @@ pub fn spawn_gameover_layout(
                         hover_handle: asset_server.load("sprites/Gameover/Buttons/Retry hovered.png"),
                         click_handle: asset_server.load("sprites/Gameover/Buttons/Retry clicked.png"),
                     },
+                    Focusable { order: 1, color: Color::WHITE },
                     ));
                 });
         });
@@ -190,12 +204,54 @@ pub fn despawn_gameover_layout(
         .despawn_recursive();
 }
 
+fn spawn_stats_panel(
+    parent: &mut ChildBuilder,
+    stats: &RunStats,
+    time_survived: Duration,
+    asset_server: &Res<AssetServer>,
+) {
+    let secs = time_survived.as_secs();
+    let items = [
+        format!("Time {}:{:02}", secs / 60, secs % 60),
+        format!("Fish {}", stats.fish_picked),
+        format!("Fish lost {}", stats.fish_lost),
+        format!("Crackers dropped {}", stats.crackers_dropped),
+        format!("Hits {}", stats.hits_taken),
+        format!(
+            "Milk {}/{}",
+            stats.milk_drunk,
+            stats.milk_drunk + stats.milk_escaped
+        ),
+        format!("Dogs {}", stats.dogs_met),
+    ];
+
+    parent
+        .spawn(NodeBundle {
+            style: STATS_PANEL,
+            ..default()
+        })
+        .with_children(|parent| {
+            for item in items {
+                parent.spawn(TextBundle {
+                    text: Text::from_section(
+                        item,
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: 15.,
+                            color: Color::hex("23CED1").unwrap(),
+                        },
+                    ),
+                    style: STATS_ITEM,
+                    ..default()
+                });
+            }
+        });
+}
+
 fn spawn_row(
     idx: usize,
     parent: &mut ChildBuilder,
-    name: Name,
-    mut image: Handle<Image>,
-    score: u32,
+    entry: &HighScoreEntry,
     asset_server: &Res<AssetServer>,
 ) {
     // Row
@@ -227,17 +283,27 @@ fn spawn_row(
                 ..default()
             });
             // Entity's image
-            // Handle Kitty's image case
-            if name.to_string() == "Kitty" {
-                image = asset_server.load("sprites/Cat's face blue.png");
+            // Handle Kitty's image case, entries stored before co-op only
+            // have the name
+            let player = entry.player.or_else(|| {
+                (entry.name == PlayerId::One.name()).then_some(PlayerId::One)
+            });
+            if let Some(player) = player {
+                let image = asset_server.load("sprites/Cat's face blue.png");
+                let tint = player.color();
                 parent.spawn(ImageBundle {
                     image: UiImage::new(image),
-                    background_color: BackgroundColor(Color::rgb(0., 0.93, 1.)),
+                    background_color: BackgroundColor(Color::rgb(
+                        0.,
+                        0.93 * tint.g(),
+                        tint.b(),
+                    )),
                     style: CAT_FACE,
                     ..default()
                 });
             } else {
                 // Handle dogs
+                let image = asset_server.load(entry.avatar.as_str());
                 parent
                     .spawn(ImageBundle {
                         image: UiImage::new(image),
@@ -305,28 +371,44 @@ fn spawn_row(
                             });
                     });
             }
-            // Entity's name
-            parent.spawn(TextBundle {
-                text: Text::from_section(
-                    name.to_string(),
-                    TextStyle {
-                        font: asset_server.load("fonts/NicoMoji-Regular.ttf"),
-                        font_size: 21.,
-                        color: Color::hex("23CED1").unwrap(),
+            // Entity's name, date and duration of the run
+            parent
+                .spawn(NodeBundle {
+                    style: NAME_COLUMN,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text::from_section(
+                            entry.name.as_str(),
+                            TextStyle {
+                                font: asset_server
+                                    .load("fonts/NicoMoji-Regular.ttf"),
+                                font_size: 21.,
+                                color: Color::hex("23CED1").unwrap(),
+                            },
+                        ),
                         ..default()
-                    },
-                ),
-                style: Style {
-                    margin: UiRect {
-                        left: Val::Px(25.),
-                        right: Val::Px(50.),
+                    });
+                    let secs = entry.duration.as_secs();
+                    parent.spawn(TextBundle {
+                        text: Text::from_section(
+                            format!(
+                                "{} {}:{:02}",
+                                entry.date.format("%d.%m.%Y"),
+                                secs / 60,
+                                secs % 60
+                            ),
+                            TextStyle {
+                                font: asset_server
+                                    .load("fonts/FiraSans-Bold.ttf"),
+                                font_size: 13.,
+                                color: Color::hex("23CED1").unwrap(),
+                            },
+                        ),
                         ..default()
-                    },
-                    size: Size::width(Val::Px(100.)),
-                    ..Style::DEFAULT
-                },
-                ..default()
-            });
+                    });
+                });
             // A bag
             parent
                 .spawn(ImageBundle {
@@ -339,7 +421,7 @@ fn spawn_row(
                 .with_children(|parent| {
                     parent.spawn(TextBundle {
                         text: Text::from_section(
-                            score.to_string(),
+                            entry.score.to_string(),
                             TextStyle {
                                 font: asset_server
                                     .load("fonts/FiraSans-Bold.ttf"),
@@ -11,12 +11,17 @@
 // )]
 
 use asset_loader::JsonAssetLoader;
+use game::{
+    enemy::assets::{DogData, DogRoster},
+    score::assets::ProgressionData,
+};
 use bevy::{
     prelude::*,
     render::{
         settings::{WgpuFeatures, WgpuSettings},
         RenderPlugin,
     },
+    transform::TransformSystem,
     window::{WindowMode, WindowResolution},
 };
 use bevy_rapier2d::prelude::*;
@@ -25,17 +30,16 @@ use bevy_tweening::TweeningPlugin;
 #[cfg(target_arch = "wasm32")]
 use bevy_tweening::TweenCompleted;
 
-#[cfg(not(target_arch = "wasm32"))]
-use bevy_hanabi::HanabiPlugin;
-
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use audio::AudioPlugin;
+use controls::ControlsPlugin;
 use components::*;
 use file_logger_plugin::FileLoggerPlugin;
 use game::GamePlugin;
 use gameover::GameoverPlugin;
 use main_menu::MainMenuPlugin;
+use particles::{resources::ParticleBackend, ParticlesPlugin};
 use resources::{CometTimer, TextureStorage};
 use systems::*;
 use transition::TransitionPlugin;
@@ -48,9 +52,11 @@ use debug::DebugPlugin;
 // Modules in folders
 pub mod asset_loader;
 pub mod audio;
+pub mod controls;
 pub mod game;
 pub mod gameover;
 pub mod main_menu;
+pub mod particles;
 
 // Top-level modules
 mod animation;
@@ -59,6 +65,7 @@ pub mod events;
 mod file_logger_plugin;
 pub mod helper_functions;
 mod resources;
+pub mod storage;
 pub mod systems;
 mod transition;
 
@@ -73,11 +80,13 @@ const COMET_SPEED: f32 = 500.;
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
 fn main() {
+    let particle_backend = ParticleBackend::from_env();
+
     // Settings for bevy_hanabi
     let mut wgpu_settings = WgpuSettings::default();
 
     #[cfg(not(target_arch = "wasm32"))]
-    {
+    if particle_backend == ParticleBackend::Gpu {
         wgpu_settings
             .features
             .set(WgpuFeatures::VERTEX_WRITABLE_STORAGE, true);
@@ -121,7 +130,9 @@ fn main() {
 
     app
         // Asset loaders
-        .init_asset_loader::<JsonAssetLoader>()
+        .init_asset_loader::<JsonAssetLoader<DogData>>()
+        .init_asset_loader::<JsonAssetLoader<DogRoster>>()
+        .init_asset_loader::<JsonAssetLoader<ProgressionData>>()
         // Resources
         .init_resource::<CometTimer>()
         .init_resource::<TextureStorage>()
@@ -131,6 +142,7 @@ fn main() {
         .add_startup_system(spawn_background_stars)
         .add_startup_system(spawn_background_texture)
         .add_startup_system(setup_audio_assets)
+        .add_startup_system(spawn_dust)
         // States
         .add_state::<AppState>()
         // Events
@@ -138,6 +150,10 @@ fn main() {
         // Plugins
         // + 2 percents on cpu
         .add_plugin(AudioPlugin)
+        .add_plugin(ControlsPlugin)
+        .add_plugin(ParticlesPlugin {
+            backend: particle_backend,
+        })
         // +1.1 percent on cpu
         .add_plugin(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.))
         .add_plugin(GamePlugin)
@@ -158,13 +174,25 @@ fn main() {
                 spawn_periodical_comet,
                 move_comets,
                 despawn_outer_comets,
+                fit_background_texture_to_view,
             )
                 .in_base_set(CoreSet::Update),
         )
+        .add_system(
+            move_background_with_camera
+                .in_base_set(CoreSet::PostUpdate)
+                .before(TransformSystem::TransformPropagate),
+        )
         // Systems
         .add_system(
             finalize_transition_to_game.in_set(OnUpdate(AppState::MainMenu)),
         )
+        .add_system(
+            start_game_on_confirm.in_set(OnUpdate(AppState::MainMenu)),
+        )
+        .add_system(
+            start_game_on_confirm.in_set(OnUpdate(AppState::GameOver)),
+        )
         // .add_system(handle_pressing_g_key.in_set(OnUpdate(AppState::MainMenu)))
         // .add_system(handle_pressing_m_key.in_set(OnUpdate(AppState::Game)))
         // Debug ScrollView
@@ -179,24 +207,17 @@ fn main() {
     app.add_plugin(DebugPlugin);
 
     #[cfg(not(target_arch = "wasm32"))]
-    {
-        app.add_plugin(HanabiPlugin)
-            .add_startup_system(spawn_dust)
-            .add_system(exit_game);
-    }
+    app.add_system(exit_game);
 
     #[cfg(target_arch = "wasm32")]
     {
-        app.init_resource::<self::resources::DustTimer>()
-            .add_system(show_splash.in_schedule(OnEnter(AppState::Splash)))
+        app.add_system(show_splash.in_schedule(OnEnter(AppState::Splash)))
             .add_system(handle_input.in_set(OnUpdate(AppState::Splash)))
             .add_system(despawn_splash.in_schedule(OnExit(AppState::Splash)))
             .add_system(
                 finalize_transition_from_splash
                     .in_set(OnUpdate(AppState::Splash)),
-            )
-            .add_system(spawn_dust_wasm)
-            .add_system(poll_and_despawn_dust_particles);
+            );
     }
 
     app.run();
@@ -213,6 +234,8 @@ pub enum AppState {
     Game,
     #[cfg(not(target_arch = "wasm32"))]
     GameOver,
+    #[cfg(not(target_arch = "wasm32"))]
+    Controls,
 
     #[cfg(target_arch = "wasm32")]
     AudioLoading,
@@ -223,6 +246,8 @@ pub enum AppState {
     #[cfg(target_arch = "wasm32")]
     GameOver,
     #[cfg(target_arch = "wasm32")]
+    Controls,
+    #[cfg(target_arch = "wasm32")]
     #[default]
     Splash,
 }
@@ -274,10 +299,13 @@ pub fn show_splash(mut commands: Commands, asset_server: Res<AssetServer>) {
 #[cfg(target_arch = "wasm32")]
 pub fn handle_input(
     click: Res<Input<MouseButton>>,
+    action_state: Res<controls::resources::ActionState>,
     mut next_state: ResMut<NextState<AppState>>,
     mut darkenscreen_events: EventWriter<DarkenScreenEvent>,
 ) {
-    if click.pressed(MouseButton::Left) {
+    if click.pressed(MouseButton::Left)
+        || action_state.just_pressed(controls::resources::Action::Confirm)
+    {
         darkenscreen_events
             .send(DarkenScreenEvent(transition::TransitionRoute::SplashToMenu))
     }
@@ -311,5 +339,8 @@ pub fn despawn_splash(
 // 302 - Dark transition phase1: screen is black, transition from splash go
 // game.
 // 310 - Dark transition phase2: screen is transparent.
-// 400..450 - gui lives id animation, hit events.
-// 450..500 - gui lives id animation, regeneration events.
+// 400..912 - gui lives id animation, hit events (`HIT_EVENTS_OFFSET` plus
+// `HEART_IDS`, a heart per possible health of every Kitty).
+// 912..1424 - gui lives id animation, regeneration events
+// (`REGEN_EVENTS_OFFSET` plus `HEART_IDS`).
+// 1424.. - free.
@@ -42,5 +42,17 @@ macro_rules! impl_button_type {
     };
 }
 
+#[derive(Component)]
+pub struct ControlsButton;
+
+/// Switches between solo and co-op play, its text shows the current mode.
+#[derive(Component)]
+pub struct PlayModeButton;
+
+/// Switches between wobbling and schooling fish, its text shows the current
+/// mode.
+#[derive(Component)]
+pub struct FishMovementButton;
+
 impl_button_type!(PlayButton);
 impl_button_type!(QuitButton);
@@ -28,7 +28,13 @@ impl Plugin for MainMenuPlugin {
             )
             // Interaction Systems
             .add_systems(
-                (interact_with_play_button, interact_with_quit_button)
+                (
+                    interact_with_play_button,
+                    interact_with_play_mode_button,
+                    interact_with_fish_movement_button,
+                    interact_with_controls_button,
+                    interact_with_quit_button,
+                )
                     .in_set(OnUpdate(AppState::MainMenu)),
             )
             // Exit State Systems
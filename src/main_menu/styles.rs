@@ -4,6 +4,9 @@ use bevy::prelude::*;
 
 // Colors
 pub const NORMAL_BUTTON_COLOR: Color = Color::WHITE;
+pub const TEXT_BUTTON_COLOR: Color = Color::rgba(0.14, 0.81, 0.82, 0.15);
+pub const HOVERED_TEXT_BUTTON_COLOR: Color =
+    Color::rgba(0.14, 0.81, 0.82, 0.35);
 
 // Planets
 pub const PLANETS_NODE: Style = Style {
@@ -78,13 +81,19 @@ pub const BOTTOM_PART: Style = Style {
     flex_grow: 1.,
     justify_content: JustifyContent::Center,
     align_items: AlignItems::FlexEnd,
-    gap: Size::new(Val::Percent(0.), Val::Percent(16.)),
+    gap: Size::new(Val::Percent(0.), Val::Percent(10.)),
     ..Style::DEFAULT
 };
 pub const NORMAL_BUTTON_STYLE: Style = Style {
     size: Size::new(Val::Px(200.), Val::Px(80.)),
     ..Style::DEFAULT
 };
+pub const TEXT_BUTTON_STYLE: Style = Style {
+    size: Size::new(Val::Px(200.), Val::Px(50.)),
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    ..Style::DEFAULT
+};
 pub const IMAGE_STYLE: Style = Style {
     max_size: Size::new(Val::Px(454.), Val::Px(124.)),
     margin: UiRect::new(Val::Px(8.), Val::Px(8.), Val::Px(8.), Val::Px(8.)),
@@ -100,7 +109,6 @@ pub const TITLE_STYLE: Style = Style {
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
-#[allow(dead_code)]
 pub fn get_button_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
     TextStyle {
         font: asset_server.load("fonts/FiraSans-Bold.ttf"),
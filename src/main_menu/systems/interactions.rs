@@ -8,9 +8,13 @@ use crate::audio::assets::AudioSource;
 use crate::audio::resources::KiraManager;
 use crate::audio::resources::SamplePack;
 use crate::components::DarkenScreenEvent;
+use crate::game::fish::resources::FishMovement;
+use crate::game::player::resources::PlayMode;
 use crate::main_menu::animation::*;
 use crate::main_menu::components::*;
+use crate::main_menu::styles::{HOVERED_TEXT_BUTTON_COLOR, TEXT_BUTTON_COLOR};
 use crate::transition::TransitionRoute;
+use crate::AppState;
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -79,3 +83,75 @@ pub fn interact_with_quit_button(
         }
     }
 }
+
+/// Text button of the main menu, which shows its setting in the label.
+type TextButtonQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        &'static Interaction,
+        &'static mut BackgroundColor,
+        &'static Children,
+    ),
+    (Changed<Interaction>, With<T>),
+>;
+
+pub fn interact_with_play_mode_button(
+    mut button_query: TextButtonQuery<PlayModeButton>,
+    mut text_query: Query<&mut Text>,
+    mut play_mode: ResMut<PlayMode>,
+) {
+    if let Ok((interaction, mut color, children)) =
+        button_query.get_single_mut()
+    {
+        match *interaction {
+            Interaction::Clicked => {
+                *play_mode = play_mode.toggled();
+                let mut texts = text_query.iter_many_mut(children);
+                while let Some(mut text) = texts.fetch_next() {
+                    text.sections[0].value = play_mode.label().to_string();
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_TEXT_BUTTON_COLOR.into(),
+            Interaction::None => *color = TEXT_BUTTON_COLOR.into(),
+        }
+    }
+}
+
+pub fn interact_with_fish_movement_button(
+    mut button_query: TextButtonQuery<FishMovementButton>,
+    mut text_query: Query<&mut Text>,
+    mut fish_movement: ResMut<FishMovement>,
+) {
+    if let Ok((interaction, mut color, children)) =
+        button_query.get_single_mut()
+    {
+        match *interaction {
+            Interaction::Clicked => {
+                *fish_movement = fish_movement.toggled();
+                let mut texts = text_query.iter_many_mut(children);
+                while let Some(mut text) = texts.fetch_next() {
+                    text.sections[0].value = fish_movement.label().to_string();
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_TEXT_BUTTON_COLOR.into(),
+            Interaction::None => *color = TEXT_BUTTON_COLOR.into(),
+        }
+    }
+}
+
+pub fn interact_with_controls_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ControlsButton),
+        Changed<Interaction>,
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut color, _)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => next_state.set(AppState::Controls),
+            Interaction::Hovered => *color = HOVERED_TEXT_BUTTON_COLOR.into(),
+            Interaction::None => *color = TEXT_BUTTON_COLOR.into(),
+        }
+    }
+}
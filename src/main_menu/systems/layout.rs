@@ -4,7 +4,11 @@ use bevy_tweening::{lens::TransformPositionLens, *};
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
 use crate::{
-    game::player::components::Player,
+    controls::components::Focusable,
+    game::{
+        fish::resources::FishMovement,
+        player::{components::Player, resources::PlayMode},
+    },
     main_menu::{components::*, styles::*},
 };
 
@@ -21,9 +25,11 @@ pub fn spawn_main_menu(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    play_mode: Res<PlayMode>,
+    fish_movement: Res<FishMovement>,
 ) {
     build_planets_layer(&mut commands, &asset_server);
-    build_main_menu(&mut commands, &asset_server);
+    build_main_menu(&mut commands, &asset_server, *play_mode, *fish_movement);
     spawn_player(&mut commands, &asset_server, &window_query);
 }
 
@@ -112,6 +118,8 @@ pub fn build_planets_layer(
 pub fn build_main_menu(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    play_mode: PlayMode,
+    fish_movement: FishMovement,
 ) -> Entity {
     let main_menu_entity = commands
         .spawn((
@@ -147,6 +155,17 @@ pub fn build_main_menu(
                         })
                         .with_children(|parent| {
                             spawn_play_button(parent, asset_server);
+                            spawn_play_mode_button(
+                                parent,
+                                asset_server,
+                                play_mode,
+                            );
+                            spawn_fish_movement_button(
+                                parent,
+                                asset_server,
+                                fish_movement,
+                            );
+                            spawn_controls_button(parent, asset_server);
 
                             #[cfg(not(target_arch = "wasm32"))]
                             spawn_quit_button(parent, asset_server);
@@ -201,9 +220,90 @@ fn spawn_play_button(
             hover_handle: hovered_texture,
             click_handle: clicked_texture,
         },
+        Focusable {
+            order: 0,
+            color: NORMAL_BUTTON_COLOR,
+        },
     ));
 }
 
+fn spawn_play_mode_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    play_mode: PlayMode,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: TEXT_BUTTON_STYLE,
+                background_color: TEXT_BUTTON_COLOR.into(),
+                ..default()
+            },
+            PlayModeButton,
+            Focusable {
+                order: 1,
+                color: TEXT_BUTTON_COLOR,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                play_mode.label(),
+                get_button_text_style(asset_server),
+            ));
+        });
+}
+
+fn spawn_fish_movement_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    fish_movement: FishMovement,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: TEXT_BUTTON_STYLE,
+                background_color: TEXT_BUTTON_COLOR.into(),
+                ..default()
+            },
+            FishMovementButton,
+            Focusable {
+                order: 2,
+                color: TEXT_BUTTON_COLOR,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                fish_movement.label(),
+                get_button_text_style(asset_server),
+            ));
+        });
+}
+
+fn spawn_controls_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: TEXT_BUTTON_STYLE,
+                background_color: TEXT_BUTTON_COLOR.into(),
+                ..default()
+            },
+            ControlsButton,
+            Focusable {
+                order: 3,
+                color: TEXT_BUTTON_COLOR,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Controls",
+                get_button_text_style(asset_server),
+            ));
+        });
+}
+
 fn spawn_quit_button(
     parent: &mut ChildBuilder,
     asset_server: &Res<AssetServer>,
@@ -226,6 +326,10 @@ fn spawn_quit_button(
             hover_handle: hovered_texture,
             click_handle: clicked_texture,
         },
+        Focusable {
+            order: 4,
+            color: NORMAL_BUTTON_COLOR,
+        },
     ));
 }
 
@@ -255,7 +359,7 @@ fn spawn_player(
                 ..default()
             },
             Name::new("PlayerBox"),
-            Player { health: 1 },
+            Player::new(1),
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Particle effects which gameplay can emit, every backend draws them its
+/// own way.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ParticleEffectKind {
+    /// Smoke behind the Kitty's rocket engine.
+    RocketFlame,
+    /// Star dust flying around the screen center.
+    MenuDust,
+    /// Signal waves of the antenna, flying along the emitter Y axis.
+    MilkSignal,
+    /// Notes flying out of dogs while they sing.
+    Notes,
+}
+
+impl ParticleEffectKind {
+    pub const ALL: [ParticleEffectKind; 4] = [
+        ParticleEffectKind::RocketFlame,
+        ParticleEffectKind::MenuDust,
+        ParticleEffectKind::MilkSignal,
+        ParticleEffectKind::Notes,
+    ];
+
+    /// Effects without a hanabi version are drawn on CPU by any backend.
+    pub fn has_gpu_effect(&self) -> bool {
+        match self {
+            ParticleEffectKind::RocketFlame
+            | ParticleEffectKind::MenuDust
+            | ParticleEffectKind::MilkSignal => true,
+            ParticleEffectKind::Notes => false,
+        }
+    }
+}
+
+/// Emits particles of `effect` while `active`, with whatever backend was
+/// chosen at startup. Particles are emitted from the emitter transform, so
+/// it needs a `SpatialBundle`.
+#[derive(Component, Debug)]
+pub struct ParticleEmitter {
+    pub effect: ParticleEffectKind,
+    pub active: bool,
+    /// Particles to emit at once on the next frame, whether the emitter is
+    /// active or not. Only CPU effects support bursts.
+    pub burst: u32,
+}
+
+impl ParticleEmitter {
+    /// Inactive emitter of `effect`.
+    pub fn new(effect: ParticleEffectKind) -> Self {
+        ParticleEmitter {
+            effect,
+            active: false,
+            burst: 0,
+        }
+    }
+}
+
+/// Emitter which is drawn by the CPU backend.
+#[derive(Component)]
+pub struct CpuEmitter {
+    /// Ticks emission of effects with a spawn rate.
+    pub timer: Option<Timer>,
+}
+
+/// Particle of the CPU backend, it's a pooled sprite moving on its own.
+/// Hidden particles are free and wait in `CpuParticlePool`.
+#[derive(Component)]
+pub struct CpuParticle {
+    pub effect: ParticleEffectKind,
+    /// Particles die together with their emitter, as GPU ones do.
+    pub emitter: Entity,
+    pub direction: Vec2,
+    pub velocity: f32,
+    pub timer: Timer,
+}
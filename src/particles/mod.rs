@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy_hanabi::HanabiPlugin;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use self::{
+    resources::{CpuEffects, CpuParticlePool, ParticleBackend},
+    systems::cpu::*,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use self::{resources::GpuEffects, systems::gpu::*};
+
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+// ───── Constants ────────────────────────────────────────────────────────── //
+
+/// Environment variable which switches native builds to CPU particles.
+pub const PARTICLES_ENV: &str = "SPACE_KITTY_PARTICLES";
+
+const SMOKE_SIZE: Vec2 = Vec2::new(168. / 5.9, 130. / 5.9);
+const SIGNAL_SPEED: f32 = 160.;
+const SIGNAL_LIFETIME: f32 = 1.2;
+const SIGNAL_RATE: f32 = 4.;
+const SIGNAL_SIZE: Vec2 = Vec2::new(42. / 2., 63. / 2.);
+const NOTE_SIZE: Vec2 = Vec2::new(279. / 5.9, 270. / 5.9);
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Draws particles of every `ParticleEmitter` with the chosen backend, so
+/// gameplay code doesn't care which one runs.
+pub struct ParticlesPlugin {
+    pub backend: ParticleBackend,
+}
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.backend)
+            // Resources
+            .init_resource::<CpuEffects>()
+            .init_resource::<CpuParticlePool>()
+            // Systems
+            .add_systems(
+                (
+                    add_cpu_emitters.run_if(game_is_not_paused),
+                    emit_cpu_particles.run_if(game_is_not_paused),
+                    update_cpu_particles.run_if(game_is_not_paused),
+                )
+                    .chain(),
+            );
+
+        // CPU systems stay for effects which have no hanabi version
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.backend == ParticleBackend::Gpu {
+            app.add_plugin(HanabiPlugin)
+                // Resources
+                .init_resource::<GpuEffects>()
+                // Systems
+                .add_systems((attach_gpu_effects, sync_gpu_emitters));
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use bevy::{prelude::*, utils::HashMap};
+use rand::Rng;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy_hanabi::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::PARTICLES_ENV;
+use super::{
+    components::ParticleEffectKind, NOTE_SIZE, SIGNAL_LIFETIME, SIGNAL_RATE,
+    SIGNAL_SIZE, SIGNAL_SPEED, SMOKE_SIZE,
+};
+use crate::resources::TextureStorage;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Where particles are simulated. Hanabi needs compute shaders, so the CPU
+/// backend is the only one on the web and a fallback for GPUs without them.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleBackend {
+    #[cfg(not(target_arch = "wasm32"))]
+    Gpu,
+    Cpu,
+}
+
+impl ParticleBackend {
+    /// GPU backend, unless `PARTICLES_ENV` variable is set to `cpu`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_env() -> Self {
+        match std::env::var(PARTICLES_ENV) {
+            Ok(value) if value.eq_ignore_ascii_case("cpu") => {
+                ParticleBackend::Cpu
+            }
+            _ => ParticleBackend::Gpu,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_env() -> Self {
+        ParticleBackend::Cpu
+    }
+}
+
+/// Hanabi effect of every `ParticleEffectKind` which has one.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource)]
+pub struct GpuEffects(pub HashMap<ParticleEffectKind, Handle<EffectAsset>>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FromWorld for GpuEffects {
+    fn from_world(world: &mut World) -> Self {
+        let texture_storage = world.resource::<TextureStorage>();
+        let effects: Vec<_> = ParticleEffectKind::ALL
+            .into_iter()
+            .filter_map(|kind| {
+                Some((kind, effect_asset(kind, texture_storage)?))
+            })
+            .collect();
+
+        let mut assets = world.resource_mut::<Assets<EffectAsset>>();
+        GpuEffects(
+            effects
+                .into_iter()
+                .map(|(kind, effect)| (kind, assets.add(effect)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn effect_asset(
+    kind: ParticleEffectKind,
+    texture_storage: &TextureStorage,
+) -> Option<EffectAsset> {
+    let effect = match kind {
+        ParticleEffectKind::RocketFlame => {
+            let mut color_gradient = Gradient::new();
+            color_gradient.add_key(0.0, Vec4::new(0., 0.07, 0.06, 0.0));
+            color_gradient.add_key(0.2, Vec4::new(0.06, 0.02, 0.10, 0.5));
+            color_gradient.add_key(1.0, Vec4::new(0., 0., 0., 0.));
+
+            EffectAsset {
+                name: "RocketFlame".to_string(),
+                capacity: 1000,
+                spawner: Spawner::rate(70.0.into()).with_starts_active(false),
+                ..default()
+            }
+            .init(InitLifetimeModifier {
+                lifetime: 3_f32.into(),
+            })
+            .init(InitPositionCircleModifier {
+                radius: 11.,
+                ..default()
+            })
+            .init(InitVelocityCircleModifier {
+                axis: Vec3::Z,
+                speed: 30.0.into(),
+                ..default()
+            })
+            .init(InitVelocityTangentModifier {
+                speed: Value::Uniform((-20., 20.)),
+                axis: Vec3::Z,
+                ..default()
+            })
+            .render(ParticleTextureModifier {
+                texture: texture_storage.smoke.clone_weak(),
+            })
+            .render(SizeOverLifetimeModifier {
+                gradient: Gradient::constant(Vec2::splat(25.0)),
+            })
+            .render(ColorOverLifetimeModifier {
+                gradient: color_gradient,
+            })
+        }
+        ParticleEffectKind::MenuDust => {
+            let mut gradient = Gradient::new();
+            gradient.add_key(0.0, Vec4::new(1., 1., 1., 0.0));
+            gradient.add_key(0.5, Vec4::new(1., 1., 1., 0.1));
+            gradient.add_key(1.0, Vec4::ZERO);
+
+            EffectAsset {
+                name: "MenuBackgroundStars".to_string(),
+                capacity: 1000,
+                spawner: Spawner::rate(50.0.into()).with_starts_active(false),
+                ..default()
+            }
+            .init(InitPositionSphereModifier {
+                center: Vec3::ZERO,
+                radius: 500.0,
+                dimension: ShapeDimension::Surface,
+            })
+            .init(InitLifetimeModifier {
+                lifetime: 7_f32.into(),
+            })
+            .init(InitVelocityCircleModifier {
+                center: Vec3::new(5., 5., 1.),
+                axis: Vec3::Z,
+                speed: 100.0.into(),
+            })
+            .render(ParticleTextureModifier {
+                texture: texture_storage.glowing_star.clone_weak(),
+            })
+            .render(SizeOverLifetimeModifier {
+                gradient: Gradient::constant(Vec2::splat(5.0)),
+            })
+            .render(ColorOverLifetimeModifier { gradient })
+        }
+        ParticleEffectKind::MilkSignal => {
+            let mut color_gradient = Gradient::new();
+            color_gradient.add_key(0.0, Vec4::new(1., 0.95, 0.85, 0.));
+            color_gradient.add_key(0.2, Vec4::new(1., 0.95, 0.85, 0.9));
+            color_gradient.add_key(1.0, Vec4::new(1., 0.95, 0.85, 0.));
+
+            let mut size_gradient = Gradient::new();
+            size_gradient.add_key(0.0, SIGNAL_SIZE * 0.5);
+            size_gradient.add_key(1.0, SIGNAL_SIZE * 1.5);
+
+            EffectAsset {
+                name: "MilkSignal".to_string(),
+                capacity: 64,
+                spawner: Spawner::rate(SIGNAL_RATE.into())
+                    .with_starts_active(false),
+                ..default()
+            }
+            .init(InitLifetimeModifier {
+                lifetime: SIGNAL_LIFETIME.into(),
+            })
+            .init(InitPositionCircleModifier {
+                axis: Vec3::Z,
+                radius: 1.,
+                ..default()
+            })
+            // Center far behind the emitter makes all particles fly along
+            // its Y axis, which follows the antenna
+            .init(InitVelocityCircleModifier {
+                center: Vec3::new(0., -1000., 0.),
+                axis: Vec3::Z,
+                speed: SIGNAL_SPEED.into(),
+            })
+            .render(ParticleTextureModifier {
+                // Texture is turned sideways, because particle X axis is
+                // oriented along its velocity
+                texture: texture_storage.signal_sideways.clone_weak(),
+            })
+            .render(OrientAlongVelocityModifier)
+            .render(SizeOverLifetimeModifier {
+                gradient: size_gradient,
+            })
+            .render(ColorOverLifetimeModifier {
+                gradient: color_gradient,
+            })
+        }
+        ParticleEffectKind::Notes => return None,
+    };
+    Some(effect)
+}
+
+/// Alpha which rises linearly from zero to `peak` at `peak_at` part of the
+/// lifetime, then drops back to zero by the end of it.
+#[derive(Clone, Copy, Debug)]
+pub struct AlphaCurve {
+    pub peak_at: f32,
+    pub peak: f32,
+}
+
+impl AlphaCurve {
+    pub fn sample(&self, progress: f32) -> f32 {
+        if progress < self.peak_at {
+            progress / self.peak_at * self.peak
+        } else {
+            (1. - progress) / (1. - self.peak_at).max(f32::EPSILON) * self.peak
+        }
+    }
+}
+
+/// Where CPU particles fly right after they are emitted.
+#[derive(Clone, Copy, Debug)]
+pub enum EmitDirection {
+    /// Along `axis` of the emitter, turned randomly by up to `spread`
+    /// radians.
+    Along { axis: Vec2, spread: f32 },
+    /// Evenly around the emitter starting from `start`, whatever the emitter
+    /// rotation is.
+    Around { start: Vec2 },
+    /// To the emitter, from random points `distance` away from it.
+    Inward { distance: (f32, f32) },
+}
+
+/// Look and motion of CPU particles of one effect.
+pub struct CpuEffect {
+    /// Particles per second while the emitter is active, zero for effects
+    /// which are emitted in bursts only.
+    pub rate: f32,
+    pub lifetime: f32,
+    pub direction: EmitDirection,
+    /// Initial speed is random between these two.
+    pub speed: (f32, f32),
+    /// How fast particles slow down, per second.
+    pub decay: f32,
+    /// Particles appear this far from their spawn point in random direction.
+    pub jitter: f32,
+    /// Particles are drawn this much above the emitter.
+    pub z_offset: f32,
+    pub size: Vec2,
+    /// Random rotation, otherwise particles are turned as the emitter is.
+    pub random_rotation: bool,
+    /// Scale at the start and at the end of the lifetime.
+    pub scale: (f32, f32),
+    /// Color at the start and at the end of the lifetime, alpha is taken
+    /// from `alpha`.
+    pub color: (Color, Color),
+    pub alpha: AlphaCurve,
+    /// Every particle takes a random one of them.
+    pub textures: Vec<Handle<Image>>,
+}
+
+impl CpuEffect {
+    pub fn random_speed(&self) -> f32 {
+        let (min, max) = self.speed;
+        min + (max - min) * rand::thread_rng().gen::<f32>()
+    }
+
+    pub fn random_texture(&self) -> Handle<Image> {
+        let index = rand::thread_rng().gen_range(0..self.textures.len());
+        self.textures[index].clone_weak()
+    }
+
+    pub fn scale_at(&self, progress: f32) -> f32 {
+        self.scale.0 + (self.scale.1 - self.scale.0) * progress
+    }
+
+    pub fn color_at(&self, progress: f32) -> Color {
+        let start = Vec4::from(self.color.0.as_rgba_f32());
+        let end = Vec4::from(self.color.1.as_rgba_f32());
+        let mut color = Color::from(start.lerp(end, progress));
+        color.set_a(self.alpha.sample(progress));
+        color
+    }
+}
+
+/// CPU version of every `ParticleEffectKind`.
+#[derive(Resource)]
+pub struct CpuEffects(pub HashMap<ParticleEffectKind, CpuEffect>);
+
+impl FromWorld for CpuEffects {
+    fn from_world(world: &mut World) -> Self {
+        let texture_storage = world.resource::<TextureStorage>();
+        CpuEffects(
+            ParticleEffectKind::ALL
+                .into_iter()
+                .map(|kind| (kind, cpu_effect(kind, texture_storage)))
+                .collect(),
+        )
+    }
+}
+
+fn cpu_effect(
+    kind: ParticleEffectKind,
+    texture_storage: &TextureStorage,
+) -> CpuEffect {
+    match kind {
+        ParticleEffectKind::RocketFlame => CpuEffect {
+            rate: 60.,
+            lifetime: 1.9,
+            // Emitter Y axis looks into the engine
+            direction: EmitDirection::Along {
+                axis: Vec2::NEG_Y,
+                spread: 0.3,
+            },
+            speed: (100., 200.),
+            decay: 87.,
+            jitter: 20.,
+            z_offset: -1.,
+            size: SMOKE_SIZE,
+            random_rotation: true,
+            scale: (1., 1.),
+            color: (Color::rgb(0.5, 0.1, 0.29), Color::rgb(0., 0., 0.29)),
+            alpha: AlphaCurve {
+                peak_at: 0.5,
+                peak: 0.5,
+            },
+            textures: vec![texture_storage.smoke.clone_weak()],
+        },
+        ParticleEffectKind::MenuDust => CpuEffect {
+            rate: 20.,
+            lifetime: 5.5,
+            direction: EmitDirection::Inward {
+                distance: (100., 550.),
+            },
+            speed: (0.01, 0.02),
+            decay: 8.7,
+            jitter: 20.,
+            z_offset: 2.,
+            size: Vec2::splat(3.9),
+            random_rotation: true,
+            scale: (1., 1.),
+            color: (Color::WHITE, Color::WHITE),
+            alpha: AlphaCurve {
+                peak_at: 0.5,
+                peak: 0.5,
+            },
+            textures: vec![texture_storage.glowing_star.clone_weak()],
+        },
+        // Same curves as GPU particles have
+        ParticleEffectKind::MilkSignal => CpuEffect {
+            rate: SIGNAL_RATE,
+            lifetime: SIGNAL_LIFETIME,
+            direction: EmitDirection::Along {
+                axis: Vec2::Y,
+                spread: 0.,
+            },
+            speed: (SIGNAL_SPEED, SIGNAL_SPEED),
+            decay: 0.,
+            jitter: 0.,
+            z_offset: 0.,
+            // Original texture is upright, so size is swapped
+            size: Vec2::new(SIGNAL_SIZE.y, SIGNAL_SIZE.x),
+            random_rotation: false,
+            scale: (0.5, 1.5),
+            color: (Color::rgb(1., 0.95, 0.85), Color::rgb(1., 0.95, 0.85)),
+            alpha: AlphaCurve {
+                peak_at: 0.2,
+                peak: 0.9,
+            },
+            textures: vec![texture_storage.signal.clone_weak()],
+        },
+        ParticleEffectKind::Notes => CpuEffect {
+            rate: 0.,
+            lifetime: 1.5,
+            direction: EmitDirection::Around { start: Vec2::ONE },
+            speed: (100., 100.),
+            decay: 87.,
+            jitter: 0.,
+            z_offset: 0.,
+            size: NOTE_SIZE,
+            random_rotation: true,
+            scale: (1., 1.),
+            color: (Color::WHITE, Color::WHITE),
+            alpha: AlphaCurve {
+                peak_at: 0.,
+                peak: 1.,
+            },
+            textures: texture_storage.notes(),
+        },
+    }
+}
+
+/// Hidden CPU particles, which are reused instead of spawning new ones.
+#[derive(Resource, Default)]
+pub struct CpuParticlePool {
+    pub free: Vec<Entity>,
+}
+
+// ───── Unit tests ───────────────────────────────────────────────────────── //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_curve_rises_to_peak_and_fades_out() {
+        let curve = AlphaCurve {
+            peak_at: 0.2,
+            peak: 0.9,
+        };
+        assert_eq!(curve.sample(0.), 0.);
+        assert!((curve.sample(0.2) - 0.9).abs() < 1e-6);
+        assert!((curve.sample(0.6) - 0.45).abs() < 1e-6);
+        assert_eq!(curve.sample(1.), 0.);
+
+        let fade_out = AlphaCurve {
+            peak_at: 0.,
+            peak: 1.,
+        };
+        assert_eq!(fade_out.sample(0.), 1.);
+        assert!((fade_out.sample(0.25) - 0.75).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,225 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::{PI, TAU};
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::game::SimulationState;
+use crate::helper_functions::VectorUtilities;
+use crate::particles::{
+    components::{CpuEmitter, CpuParticle, ParticleEmitter},
+    resources::{
+        CpuEffect, CpuEffects, CpuParticlePool, EmitDirection, ParticleBackend,
+    },
+};
+use crate::AppState;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Particles freeze together with the paused game, menus keep theirs
+/// flying, as simulation stays paused out of the game.
+pub fn game_is_not_paused(
+    app_state: Res<State<AppState>>,
+    simulation_state: Res<State<SimulationState>>,
+) -> bool {
+    app_state.0 != AppState::Game
+        || simulation_state.0 == SimulationState::Running
+}
+
+/// Marks emitters which the CPU backend has to draw: all of them when it's
+/// the chosen one, otherwise only effects without a hanabi version.
+pub fn add_cpu_emitters(
+    mut commands: Commands,
+    emitter_query: Query<(Entity, &ParticleEmitter), Added<ParticleEmitter>>,
+    effects: Res<CpuEffects>,
+    backend: Res<ParticleBackend>,
+) {
+    for (entity, emitter) in emitter_query.iter() {
+        let on_cpu = match *backend {
+            #[cfg(not(target_arch = "wasm32"))]
+            ParticleBackend::Gpu => !emitter.effect.has_gpu_effect(),
+            ParticleBackend::Cpu => true,
+        };
+        if !on_cpu {
+            continue;
+        }
+
+        let rate = effects.0[&emitter.effect].rate;
+        let timer = (rate > 0.)
+            .then(|| Timer::from_seconds(1. / rate, TimerMode::Repeating));
+        commands.entity(entity).insert(CpuEmitter { timer });
+    }
+}
+
+pub fn emit_cpu_particles(
+    mut commands: Commands,
+    mut emitter_query: Query<(
+        Entity,
+        &mut ParticleEmitter,
+        &GlobalTransform,
+        &mut CpuEmitter,
+    )>,
+    mut particles_query: Query<(
+        &mut Sprite,
+        &mut Transform,
+        &mut Handle<Image>,
+        &mut Visibility,
+        &mut CpuParticle,
+    )>,
+    mut pool: ResMut<CpuParticlePool>,
+    effects: Res<CpuEffects>,
+    time: Res<Time>,
+) {
+    for (entity, mut emitter, transform, mut cpu_emitter) in
+        emitter_query.iter_mut()
+    {
+        let mut count = std::mem::take(&mut emitter.burst);
+        if let Some(timer) = &mut cpu_emitter.timer {
+            timer.tick(time.delta());
+            if emitter.active {
+                count += timer.times_finished_this_tick();
+            }
+        }
+
+        let effect = &effects.0[&emitter.effect];
+        let transform = transform.compute_transform();
+        for index in 0..count {
+            let (sprite, particle_transform, particle) = new_particle(
+                entity, &emitter, effect, &transform, index, count,
+            );
+            let texture = effect.random_texture();
+
+            let free = pool
+                .free
+                .pop()
+                .and_then(|free| particles_query.get_mut(free).ok());
+            match free {
+                Some((
+                    mut free_sprite,
+                    mut free_transform,
+                    mut free_texture,
+                    mut visibility,
+                    mut free_particle,
+                )) => {
+                    *free_sprite = sprite;
+                    *free_transform = particle_transform;
+                    *free_texture = texture;
+                    *visibility = Visibility::Inherited;
+                    *free_particle = particle;
+                }
+                None => {
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite,
+                            transform: particle_transform,
+                            texture,
+                            ..default()
+                        },
+                        particle,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Starting state of the `index` particle out of `count` emitted at once.
+fn new_particle(
+    entity: Entity,
+    emitter: &ParticleEmitter,
+    effect: &CpuEffect,
+    transform: &Transform,
+    index: u32,
+    count: u32,
+) -> (Sprite, Transform, CpuParticle) {
+    let mut rng = rand::thread_rng();
+    let origin = transform.translation.truncate();
+
+    let mut position = match effect.direction {
+        EmitDirection::Inward {
+            distance: (min, max),
+        } => origin + Vec2::new_rand() * rng.gen_range(min..max),
+        _ => origin,
+    };
+    if effect.jitter > 0. {
+        position += Vec2::new_rand() * effect.jitter;
+    }
+
+    let direction = match effect.direction {
+        EmitDirection::Along { axis, spread } => (transform.rotation
+            * axis.extend(0.))
+        .truncate()
+        .normalize_or_zero()
+        .rotated((rng.gen::<f32>() * 2. - 1.) * spread),
+        EmitDirection::Around { start } => {
+            start.normalize().rotated(TAU / count as f32 * index as f32)
+        }
+        EmitDirection::Inward { .. } => (origin - position).normalize_or_zero(),
+    };
+
+    let rotation = if effect.random_rotation {
+        Quat::from_rotation_z(rng.gen_range(-PI..PI))
+    } else {
+        transform.rotation
+    };
+
+    (
+        Sprite {
+            custom_size: Some(effect.size),
+            color: effect.color_at(0.),
+            ..default()
+        },
+        Transform::from_translation(
+            position.extend(transform.translation.z + effect.z_offset),
+        )
+        .with_rotation(rotation)
+        .with_scale(Vec3::splat(effect.scale_at(0.))),
+        CpuParticle {
+            effect: emitter.effect,
+            emitter: entity,
+            direction,
+            velocity: effect.random_speed(),
+            timer: Timer::from_seconds(effect.lifetime, TimerMode::Once),
+        },
+    )
+}
+
+pub fn update_cpu_particles(
+    emitter_query: Query<(), With<ParticleEmitter>>,
+    mut particles_query: Query<(
+        Entity,
+        &mut Sprite,
+        &mut Transform,
+        &mut Visibility,
+        &mut CpuParticle,
+    )>,
+    mut pool: ResMut<CpuParticlePool>,
+    effects: Res<CpuEffects>,
+    time: Res<Time>,
+) {
+    for (entity, mut sprite, mut transform, mut visibility, mut particle) in
+        particles_query.iter_mut()
+    {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        if particle.timer.tick(time.delta()).finished()
+            || !emitter_query.contains(particle.emitter)
+        {
+            *visibility = Visibility::Hidden;
+            pool.free.push(entity);
+            continue;
+        }
+
+        let effect = &effects.0[&particle.effect];
+        let step =
+            particle.direction * particle.velocity * time.delta_seconds();
+        transform.translation += step.extend(0.);
+        particle.velocity -= effect.decay * time.delta_seconds();
+
+        let progress = particle.timer.percent();
+        transform.scale = Vec3::splat(effect.scale_at(progress));
+        sprite.color = effect.color_at(progress);
+    }
+}
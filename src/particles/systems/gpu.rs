@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_hanabi::*;
+
+// ───── Current Crate Imports ────────────────────────────────────────────── //
+
+use crate::particles::{
+    components::{ParticleEffectKind, ParticleEmitter},
+    resources::GpuEffects,
+};
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+/// Turns new emitters into hanabi effects, emitters of effects without one
+/// are left to the CPU backend.
+pub fn attach_gpu_effects(
+    mut commands: Commands,
+    emitter_query: Query<(Entity, &ParticleEmitter), Added<ParticleEmitter>>,
+    effects: Res<GpuEffects>,
+) {
+    for (entity, emitter) in emitter_query.iter() {
+        let Some(effect) = effects.0.get(&emitter.effect).cloned() else {
+            continue;
+        };
+        commands.entity(entity).insert((
+            ParticleEffect::new(effect)
+                .with_z_layer_2d(Some(z_layer(emitter.effect))),
+            CompiledParticleEffect::default(),
+        ));
+    }
+}
+
+/// Hanabi adds spawners on its own a frame after the effect, so they are
+/// checked every frame rather than on emitter changes.
+pub fn sync_gpu_emitters(
+    mut spawner_query: Query<(&ParticleEmitter, &mut EffectSpawner)>,
+) {
+    for (emitter, mut spawner) in spawner_query.iter_mut() {
+        if spawner.is_active() != emitter.active {
+            spawner.set_active(emitter.active);
+        }
+    }
+}
+
+fn z_layer(effect: ParticleEffectKind) -> f32 {
+    match effect {
+        ParticleEffectKind::MenuDust => 4.,
+        ParticleEffectKind::RocketFlame
+        | ParticleEffectKind::MilkSignal
+        | ParticleEffectKind::Notes => 0.,
+    }
+}
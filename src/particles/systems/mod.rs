@@ -0,0 +1,5 @@
+// ───── Submodules ───────────────────────────────────────────────────────── //
+
+pub mod cpu;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod gpu;
@@ -20,6 +20,8 @@ pub struct TextureStorage {
     pub(super) komet_red: Handle<Image>,
     pub(super) glowing_star: Handle<Image>,
     pub smoke: Handle<Image>,
+    pub signal: Handle<Image>,
+    pub signal_sideways: Handle<Image>,
     pub note1: Handle<Image>,
     pub note2: Handle<Image>,
     pub note3: Handle<Image>,
@@ -30,6 +32,23 @@ pub struct TextureStorage {
     pub note8: Handle<Image>,
 }
 
+impl TextureStorage {
+    pub fn notes(&self) -> Vec<Handle<Image>> {
+        [
+            &self.note1,
+            &self.note2,
+            &self.note3,
+            &self.note4,
+            &self.note5,
+            &self.note6,
+            &self.note7,
+            &self.note8,
+        ]
+        .map(Handle::clone_weak)
+        .to_vec()
+    }
+}
+
 impl FromWorld for TextureStorage {
     fn from_world(world: &mut World) -> Self {
         let asset_server = world.resource::<AssetServer>();
@@ -38,6 +57,9 @@ impl FromWorld for TextureStorage {
             komet_purple: asset_server.load("sprites/Komet Purple.png"),
             komet_red: asset_server.load("sprites/Komet Red.png"),
             smoke: asset_server.load("sprites/Smoke.png"),
+            signal: asset_server.load("sprites/Emmiting signal particle.png"),
+            signal_sideways: asset_server
+                .load("sprites/Emmiting signal particle sideways.png"),
             glowing_star: asset_server.load("sprites/Star glowing.png"),
             note1: asset_server.load("sprites/Notes/Dotted half note.png"),
             note2: asset_server.load("sprites/Notes/Eighth note.png"),
@@ -50,14 +72,3 @@ impl FromWorld for TextureStorage {
         }
     }
 }
-
-#[cfg(target_arch = "wasm32")]
-#[derive(Resource)]
-pub struct DustTimer(pub Timer);
-
-#[cfg(target_arch = "wasm32")]
-impl Default for DustTimer {
-    fn default() -> Self {
-        DustTimer(Timer::from_seconds(0.05, TimerMode::Repeating))
-    }
-}
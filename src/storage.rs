@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+// ───── Body ─────────────────────────────────────────────────────────────── //
+
+#[derive(Debug)]
+pub enum StorageError {
+    Unavailable,
+    Io(String),
+    Format(String),
+    UnsupportedVersion(u32),
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::Unavailable => write!(f, "storage is unavailable"),
+            StorageError::Io(e) => write!(f, "io error: {}", e),
+            StorageError::Format(e) => write!(f, "format error: {}", e),
+            StorageError::UnsupportedVersion(v) => {
+                write!(f, "unsupported data version: {}", v)
+            }
+        }
+    }
+}
+
+/// Place where serialized player data lives between sessions.
+pub trait StorageBackend: Send + Sync {
+    /// Returns `None` if nothing was stored yet.
+    fn read(&self) -> Result<Option<String>, StorageError>;
+    fn write(&self, data: &str) -> Result<(), StorageError>;
+}
+
+/// Checks `version` field of stored json before parsing the rest of it, so
+/// we don't fail on data of unknown layout.
+pub fn check_version(data: &str, expected: u32) -> Result<(), StorageError> {
+    let version = serde_json::from_str::<serde_json::Value>(data)
+        .map_err(|e| StorageError::Format(e.to_string()))?
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .ok_or(StorageError::Format(String::from("no version field")))?
+        as u32;
+
+    if version != expected {
+        return Err(StorageError::UnsupportedVersion(version));
+    }
+    Ok(())
+}
+
+/// Stores data as a json file in the platform data directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileBackend {
+    path: Option<std::path::PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileBackend {
+    pub fn new(name: &str) -> Self {
+        FileBackend {
+            path: dirs::data_dir().map(|dir| {
+                dir.join(env!("CARGO_PKG_NAME"))
+                    .join(format!("{}.json", name))
+            }),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl StorageBackend for FileBackend {
+    fn read(&self) -> Result<Option<String>, StorageError> {
+        let path = self.path.as_ref().ok_or(StorageError::Unavailable)?;
+        match std::fs::read_to_string(path) {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(StorageError::Io(e.to_string())),
+        }
+    }
+
+    fn write(&self, data: &str) -> Result<(), StorageError> {
+        let path = self.path.as_ref().ok_or(StorageError::Unavailable)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| StorageError::Io(e.to_string()))?;
+        }
+        std::fs::write(path, data).map_err(|e| StorageError::Io(e.to_string()))
+    }
+}
+
+/// Stores data in the browser's `localStorage`.
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStorageBackend {
+    key: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStorageBackend {
+    pub fn new(name: &str) -> Self {
+        LocalStorageBackend {
+            key: format!("space_kitty.{}", name),
+        }
+    }
+
+    fn storage(&self) -> Result<web_sys::Storage, StorageError> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or(StorageError::Unavailable)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl StorageBackend for LocalStorageBackend {
+    fn read(&self) -> Result<Option<String>, StorageError> {
+        self.storage()?
+            .get_item(&self.key)
+            .map_err(|e| StorageError::Io(format!("{:?}", e)))
+    }
+
+    fn write(&self, data: &str) -> Result<(), StorageError> {
+        self.storage()?
+            .set_item(&self.key, data)
+            .map_err(|e| StorageError::Io(format!("{:?}", e)))
+    }
+}
+
+/// Returns platform storage for data with given `name`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn default_backend(name: &str) -> Box<dyn StorageBackend> {
+    Box::new(FileBackend::new(name))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn default_backend(name: &str) -> Box<dyn StorageBackend> {
+    Box::new(LocalStorageBackend::new(name))
+}
+
+/// In-memory backend for tests, clones share the same stored data.
+#[cfg(test)]
+#[derive(Default, Clone)]
+pub struct MemoryBackend(pub std::sync::Arc<std::sync::Mutex<Option<String>>>);
+
+#[cfg(test)]
+impl StorageBackend for MemoryBackend {
+    fn read(&self) -> Result<Option<String>, StorageError> {
+        Ok(self.0.lock().unwrap().clone())
+    }
+
+    fn write(&self, data: &str) -> Result<(), StorageError> {
+        *self.0.lock().unwrap() = Some(data.to_string());
+        Ok(())
+    }
+}
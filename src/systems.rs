@@ -9,18 +9,19 @@ use bevy_rapier2d::prelude::*;
 use bevy_tweening::*;
 use rand::Rng;
 
-#[cfg(not(target_arch = "wasm32"))]
-use bevy_hanabi::*;
-
 // ───── Current Crate Imports ────────────────────────────────────────────── //
 
+use crate::controls::resources::{Action, ActionState, MenuFocus};
+use crate::game::components::BackgroundTexture;
+use crate::game::score::resources::{HighScores, ScoreOwner};
+use crate::game::SimulationState;
+use crate::particles::components::{ParticleEffectKind, ParticleEmitter};
 use crate::resources::CometTimer;
 use crate::AppState;
 use crate::{animation::*, RAND_STAR_ANIMATION_TIME_RANGE};
 use crate::{audio::resources::SamplePack, COMET_SPEED};
 use crate::{components::*, resources::TextureStorage};
 use crate::{events::*, transition::TransitionRoute};
-use crate::{game::SimulationState, helper_functions::VectorUtilities};
 
 // ───── Body ─────────────────────────────────────────────────────────────── //
 
@@ -31,7 +32,12 @@ pub fn setup(
     // Setup physics gravity
     rapier_config.gravity = Vec2::ZERO;
 
-    commands.spawn((SpatialBundle::default(), Comets, Name::new("Comets")));
+    commands.spawn((
+        SpatialBundle::default(),
+        Comets,
+        BackgroundLayer { follow: 1. },
+        Name::new("Comets"),
+    ));
 }
 
 pub fn setup_audio_assets(
@@ -187,162 +193,64 @@ pub fn spawn_background_texture(
     asset_server: Res<AssetServer>,
 ) {
     let window = window_query.get_single().unwrap();
-    commands.spawn(SpriteBundle {
-        sprite: Sprite {
-            custom_size: Some(Vec2::new(window.width(), window.height())),
-            color: Color::rgba(1., 1., 1., 0.999),
-            ..default()
-        },
-        texture: asset_server.load("sprites/Background.png"),
-        transform: Transform::from_xyz(
-            window.width() / 2.,
-            window.height() / 2.,
-            0.,
-        ),
-        ..default()
-    });
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-pub fn spawn_dust(
-    mut commands: Commands,
-    texture_storage: Res<TextureStorage>,
-    mut effects: ResMut<Assets<EffectAsset>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-) {
-    let mut gradient = Gradient::new();
-    gradient.add_key(0.0, Vec4::new(1., 1., 1., 0.0));
-    gradient.add_key(0.5, Vec4::new(1., 1., 1., 0.1));
-    gradient.add_key(1.0, Vec4::ZERO);
-
-    // Create the effect asset
-    let effect = effects.add(
-        EffectAsset {
-            name: "MenuBackgroundStars".to_string(),
-            capacity: 1000,
-            spawner: Spawner::rate(50.0.into()),
-            ..default()
-        }
-        .init(InitPositionSphereModifier {
-            center: Vec3::ZERO,
-            radius: 500.0,
-            dimension: ShapeDimension::Surface,
-        })
-        .init(InitLifetimeModifier {
-            lifetime: 7_f32.into(),
-        })
-        .init(InitVelocityCircleModifier {
-            center: Vec3::new(5., 5., 1.),
-            axis: Vec3::Z,
-            speed: 100.0.into(),
-        })
-        .render(ParticleTextureModifier {
-            texture: texture_storage.glowing_star.clone_weak(),
-        })
-        .render(SizeOverLifetimeModifier {
-            gradient: Gradient::constant(Vec2::splat(5.0)),
-        })
-        .render(ColorOverLifetimeModifier { gradient }),
-    );
-
-    let window = window_query.single();
-
-    commands
-        .spawn(ParticleEffectBundle {
-            effect: ParticleEffect::new(effect).with_z_layer_2d(Some(4.)),
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(window.width(), window.height())),
+                color: Color::rgba(1., 1., 1., 0.999),
+                ..default()
+            },
+            texture: asset_server.load("sprites/Background.png"),
             transform: Transform::from_xyz(
                 window.width() / 2.,
                 window.height() / 2.,
                 0.,
             ),
             ..default()
-        })
-        .insert(Name::new("StarsInMenu"));
+        },
+        BackgroundLayer { follow: 1. },
+        BackgroundTexture,
+    ));
 }
 
-#[cfg(target_arch = "wasm32")]
-pub fn spawn_dust_wasm(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    mut timer: ResMut<super::resources::DustTimer>,
-    time: Res<Time>,
+/// Stretches the backdrop over the camera view, which changes together
+/// with the window.
+pub fn fit_background_texture_to_view(
+    camera_query: Query<
+        &OrthographicProjection,
+        (With<Camera2d>, Changed<OrthographicProjection>),
+    >,
+    mut background_query: Query<&mut Sprite, With<BackgroundTexture>>,
 ) {
-    if timer.0.tick(time.delta()).just_finished() {
-        let window = window_query.single();
-        let mut center = Vec2::new(window.width() / 2., window.height() / 2.);
-        center.x -= 25.0;
-        let mut rng = rand::thread_rng();
-
-        let pi = std::f32::consts::PI;
-        let direction = Vec2::ONE.rotated(rng.gen_range(-pi..pi));
-        let velocity = rng.gen_range(0.1..0.2);
-        let timer = Timer::from_seconds(5.5, TimerMode::Once);
-        let position = (center
-            + direction * rng.gen_range(100.0..550.0)
-            + Vec2::new(rng.gen(), rng.gen()).normalize() * 20.)
-            .extend(2.);
-        let direction = (center - position.truncate()).normalize();
-
-        commands.spawn((
-            SpriteBundle {
-                sprite: Sprite {
-                    custom_size: Some(Vec2::splat(3.9)),
-                    color: Color::rgba(1., 1., 1., 0.),
-                    ..default()
-                },
-                transform: Transform::from_translation(position)
-                    .with_rotation(Quat::from_rotation_z(rng.gen())),
-                texture: asset_server.load("sprites/Star glowing.png"),
-                ..default()
-            },
-            DustParticle {
-                direction,
-                velocity,
-                timer,
-            },
-        ));
+    let Ok(projection) = camera_query.get_single() else {
+        return;
+    };
+    let size = projection.area.size();
+    for mut sprite in background_query.iter_mut() {
+        if sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-pub fn poll_and_despawn_dust_particles(
+pub fn spawn_dust(
     mut commands: Commands,
-    mut particles_query: Query<(
-        Entity,
-        &mut Sprite,
-        &mut Transform,
-        &mut DustParticle,
-    )>,
-    time: Res<Time>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
 ) {
-    for (entity, mut sprite, mut transform, mut particle) in
-        particles_query.iter_mut()
-    {
-        if particle.timer.tick(time.delta()).finished() {
-            commands.entity(entity).despawn();
-        } else {
-            let x = particle.direction.x
-                * time.delta_seconds()
-                * particle.velocity
-                * 0.1;
-            let y = particle.direction.y
-                * time.delta_seconds()
-                * particle.velocity
-                * 0.1;
-
-            transform.translation.x += x;
-            transform.translation.y += y;
-            let diff = 1.0 - particle.timer.percent_left();
-            let alpha = if diff < 0.5 {
-                diff
-            } else {
-                particle.timer.percent_left()
-            };
-            sprite.color.set_a(alpha);
-            particle.velocity = particle.velocity - time.delta_seconds() * 87.;
-        }
-    }
+    let window = window_query.single();
+
+    commands.spawn((
+        ParticleEmitter {
+            active: true,
+            ..ParticleEmitter::new(ParticleEffectKind::MenuDust)
+        },
+        SpatialBundle::from_transform(Transform::from_xyz(
+            window.width() / 2.,
+            window.height() / 2.,
+            0.,
+        )),
+        Name::new("StarsInMenu"),
+    ));
 }
 
 pub fn spawn_background_stars(
@@ -391,10 +299,36 @@ pub fn spawn_background_stars(
             transform: Transform::from_xyz(0., 0., 1.),
             ..default()
         })
+        .insert(BackgroundLayer { follow: 0.95 })
         .insert(Name::new("BackgroundStars"))
         .push_children(&children);
 }
 
+/// Keeps the backdrop in view while the camera follows Kitty around the
+/// arena.
+pub fn move_background_with_camera(
+    camera_query: Query<&Transform, With<Camera2d>>,
+    mut layers_query: Query<
+        (&mut Transform, &BackgroundLayer),
+        Without<Camera2d>,
+    >,
+    mut last_position: Local<Option<Vec2>>,
+) {
+    let Ok(camera) = camera_query.get_single() else {
+        return;
+    };
+    let position = camera.translation.truncate();
+    let shift = position - last_position.unwrap_or(position);
+    *last_position = Some(position);
+    if shift == Vec2::ZERO {
+        return;
+    }
+
+    for (mut transform, layer) in layers_query.iter_mut() {
+        transform.translation += (shift * layer.follow).extend(0.);
+    }
+}
+
 pub fn update_background_stars(
     mut commands: Commands,
     mut star_query: Query<(Entity, &mut BackgroundStar)>,
@@ -459,14 +393,16 @@ pub fn handle_pressing_g_key(
 
 pub fn debug_pressing_o_key(
     keyboard_input: Res<Input<KeyCode>>,
-    mut highscores: ResMut<crate::game::score::resources::HighScores>,
+    mut highscores: ResMut<HighScores>,
     mut event_writer: EventWriter<GameOver>,
     asset_server: Res<AssetServer>,
 ) {
     if keyboard_input.just_pressed(KeyCode::O) {
         for i in 0..10 {
             highscores.scores.insert(
-                Name::new("Kitty".to_string() + &i.to_string()),
+                ScoreOwner::Dog(Name::new(
+                    "Kitty".to_string() + &i.to_string(),
+                )),
                 (asset_server.load("sprites/Cat's face blue.png"), i),
             );
         }
@@ -487,12 +423,32 @@ pub fn handle_pressing_m_key(
     }
 }
 
+pub fn start_game_on_confirm(
+    action_state: Res<ActionState>,
+    menu_focus: Res<MenuFocus>,
+    mut event_writer: EventWriter<DarkenScreenEvent>,
+) {
+    // With a focused button Confirm presses it instead
+    if menu_focus.0.is_none() && action_state.just_pressed(Action::Confirm) {
+        event_writer.send(DarkenScreenEvent(TransitionRoute::MenuToGame));
+    }
+}
+
+/// Back leaves the game only from the main menu, in the game it pauses
+/// the run and game over screen returns into main menu.
 pub fn exit_game(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_state: Res<ActionState>,
+    app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
     mut event_writer: EventWriter<AppExit>,
 ) {
-    if keyboard_input.pressed(KeyCode::Escape) {
-        event_writer.send(AppExit);
+    if !action_state.just_pressed(Action::Back) {
+        return;
+    }
+    match app_state.0 {
+        AppState::MainMenu => event_writer.send(AppExit),
+        AppState::GameOver => next_app_state.set(AppState::MainMenu),
+        _ => {}
     }
 }
 